@@ -1,4 +1,24 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
 fn main() {
 	println!("cargo:rerun-if-changed=build.rs");
-	println!("cargo:rustc-link-search=dependency=src/gen");
-}
\ No newline at end of file
+
+	// The parser and lexer are generated on every build from their sources, so they can never fall behind them
+	lalrpop::process_root().expect("Failed to generate the parser");
+
+	println!("cargo:rerun-if-changed=src/lexer.l");
+	println!("cargo:rerun-if-env-changed=LEX");
+	let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("Cargo always sets OUT_DIR"));
+	let lexer_c = out_dir.join("lexer.c");
+	let status = Command::new(env::var_os("LEX").unwrap_or_else(|| "flex".into()))
+		.arg("-o")
+		.arg(&lexer_c)
+		.arg("--warn")
+		.arg("src/lexer.l")
+		.status()
+		.expect("Failed to run flex; set LEX to use another lexer generator");
+	assert!(status.success(), "flex couldn't generate the lexer");
+	cc::Build::new().file(&lexer_c).compile("lexer");
+}
@@ -1,30 +1,71 @@
 pub mod ast;
 pub mod lir;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Diagnostic, LIRError, LIRErrorType, TargetError};
 
 #[derive(Default, Debug)]
 struct StackScope {
 	vars: HashMap<String, lir::Decl>,
 }
 
+/// What is known about locals declared without a value at one point in a function
+#[derive(Default, Debug, Clone)]
+struct InitState {
+	/// Locals that may not have been assigned yet
+	uninit: HashSet<lir::Ident>,
+	/// Locals that may have been assigned already
+	assigned: HashSet<lir::Ident>,
+}
+
+impl InitState {
+	/// Combine with the state of another path reaching the same point
+	fn merge(&mut self, other: InitState) {
+		self.uninit.extend(other.uninit);
+		self.assigned.extend(other.assigned);
+	}
+}
+
 #[derive(Debug)]
 struct NameResolveMap {
 	local_fns: HashMap<lir::Ident, lir::DeclFn>,
+	/// Full paths of `extern` functions declared in a namespace
+	extern_paths: HashMap<Vec<String>, lir::Ident>,
 	scope_stack: Vec<StackScope>,
+	/// Which locals declared without a value have been assigned at the point being lowered
+	init: InitState,
+	/// Return type of the function currently being lowered
+	returns: Option<lir::Type>,
+	/// How many expressions are being lowered inside each other
+	depth: usize,
+	recursion_limit: usize,
+	target: lir::TargetWidths,
+	warnings: Vec<crate::error::Diagnostic>,
 }
 
 impl NameResolveMap {
-	fn new() -> NameResolveMap {
+	fn new(recursion_limit: usize, target: lir::TargetWidths) -> NameResolveMap {
 		NameResolveMap {
 			local_fns: HashMap::new(),
+			extern_paths: HashMap::new(),
 			scope_stack: Vec::new(),
+			init: InitState::default(),
+			returns: None,
+			depth: 0,
+			recursion_limit,
+			target,
+			warnings: Vec::new(),
 		}
 	}
 
 	fn resolve_fn_default(&self, name: Vec<String>) -> Option<&lir::DeclFn> {
-		let id = lir::Ident::UnmangledItem(name[0].clone()); //TODO
-		self.local_fns.get(&id) //TODO
+		if name.len() == 1 {
+			self.local_fns.get(&lir::Ident::Function(name.clone()))
+				.or_else(|| self.local_fns.get(&lir::Ident::UnmangledItem(name[0].clone()))) //TODO
+		} else {
+			self.extern_paths.get(&name).and_then(|id| self.local_fns.get(id))
+		}
 	}
 
 	fn resolve_var_default(&self, name: Vec<String>) -> Option<lir::Decl> {
@@ -44,27 +85,133 @@ impl NameResolveMap {
 
 	fn resolve_typename_default(&self, name: Vec<String>) -> Option<lir::Ident> {
 		match &*name[0] {
-			"i8"|"i16"|"i32"|"i64"|"c_char"|"c_short"|"c_int"|"c_long"|"c_longlong" if name.len() == 1 => Some(lir::Ident::UnmangledItem(name[0].clone())),
-			_ => todo!(),
+			primitive if name.len() == 1 && lir::PRIMITIVE_NAMES.contains(&primitive) => Some(lir::Ident::UnmangledItem(name[0].clone())),
+			_ => None,
 		}
 	}
+
+	/// Error for a variable that couldn't be resolved, suggesting a similarly named variable or function in scope
+	fn unresolved_var(&self, name: &[String], span: (usize, usize)) -> LIRError {
+		let candidates = self.scope_stack.iter()
+			.flat_map(|scope| scope.vars.keys().cloned())
+			.chain(self.local_fns.keys().map(lir::Ident::source_name));
+		unresolved(name, span, candidates)
+	}
+
+	/// Error for a function that couldn't be resolved, suggesting a similarly named function
+	fn unresolved_fn(&self, name: &[String], span: (usize, usize)) -> LIRError {
+		unresolved(name, span, self.local_fns.keys().map(lir::Ident::source_name))
+	}
+
+	/// Error for a type that couldn't be resolved, suggesting a similarly named type
+	fn unresolved_typename(&self, name: &[String], span: (usize, usize)) -> LIRError {
+		unresolved(name, span, lir::PRIMITIVE_NAMES.iter().map(|&name| name.to_owned()))
+	}
 }
 
-use unique_id::Generator;
+fn unresolved(name: &[String], span: (usize, usize), candidates: impl Iterator<Item = String>) -> LIRError {
+	let name = name.join("::");
+	// Only suggest names that are close enough to plausibly be a typo
+	let threshold = std::cmp::max(1, name.chars().count() / 3);
+	let suggestion = candidates
+		.map(|candidate| (edit_distance(&name, &candidate), candidate))
+		.filter(|(distance, _)| *distance <= threshold)
+		.min_by_key(|(distance, _)| *distance)
+		.map(|(_, candidate)| candidate);
+	LIRError { ty: LIRErrorType::UnresolvedIdent(name, suggestion), span: Some(span) }
+}
 
-use inkwell::{IntPredicate, context::Context, values::{BasicValue, GlobalValue}};
+/// Levenshtein distance between two strings, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b = b.chars().collect::<Vec<_>>();
+	let mut previous = (0..=b.len()).collect::<Vec<_>>();
+	for (i, ca) in a.chars().enumerate() {
+		let mut current = vec![i + 1];
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+			current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+		}
+		previous = current;
+	}
+	previous[b.len()]
+}
+
+use std::cell::Cell;
+
+use inkwell::{FloatPredicate, IntPredicate, context::Context, values::{BasicValue, GlobalValue}};
+use inkwell::attributes::{Attribute, AttributeLoc};
 use inkwell::builder::Builder;
 use inkwell::basic_block::BasicBlock;
 use inkwell::module::{Module, Linkage};
-use inkwell::targets::{Target, TargetMachine, RelocMode, CodeModel, FileType, InitializationConfig};
+use inkwell::targets::{Target, TargetMachine, TargetTriple, RelocMode, CodeModel, FileType, InitializationConfig};
 use inkwell::types::{BasicType, BasicTypeEnum};
-use inkwell::values::{FunctionValue, PointerValue, BasicValueEnum};
-use inkwell::{AddressSpace, OptimizationLevel};
+use inkwell::values::{FunctionValue, IntValue, PointerValue, BasicValueEnum};
+use inkwell::{AddressSpace, GlobalVisibility, OptimizationLevel};
+
+/// Settings that affect how a [`Compiler`] generates code
+#[derive(Debug, Clone)]
+pub struct CompilerOptions {
+	pub reloc_mode: RelocMode,
+	pub code_model: CodeModel,
+	/// Symbol the `entry` block is compiled to
+	pub entry_symbol: String,
+	/// Target triple to generate code for, or `None` for the host
+	pub target_triple: Option<String>,
+	/// Mark where each local is in scope, so locals in disjoint blocks can share stack space
+	pub lifetime_markers: bool,
+	/// Keep the frame pointer in every function, so profilers can walk the stack
+	pub frame_pointers: bool,
+	/// Give functions that aren't `export`ed hidden visibility, so they stay out of a shared library's symbol table
+	pub hidden_visibility: bool,
+}
+
+impl Default for CompilerOptions {
+	fn default() -> CompilerOptions {
+		CompilerOptions {
+			reloc_mode: RelocMode::Default,
+			code_model: CodeModel::Default,
+			entry_symbol: "main".to_owned(),
+			target_triple: None,
+			lifetime_markers: true,
+			frame_pointers: false,
+			hidden_visibility: false,
+		}
+	}
+}
+
+/// The parts of a target triple that decide the sizes of C's types and how C functions are called
+#[derive(Debug)]
+struct TripleInfo {
+	arch: String,
+	windows: bool,
+}
+
+impl TripleInfo {
+	/// Pick apart an `arch-vendor-os-env` triple, where everything after the architecture is optional except that there
+	/// has to be something. Returns `None` if there's only an architecture.
+	fn parse(triple: &str) -> Option<TripleInfo> {
+		let mut parts = triple.split('-');
+		let arch = parts.next().filter(|arch| !arch.is_empty())?;
+		let rest = parts.collect::<Vec<_>>();
+		if rest.is_empty() {
+			return None;
+		}
+		Some(TripleInfo {
+			arch: arch.to_owned(),
+			windows: rest.iter().any(|part| part.starts_with("windows") || part.starts_with("win32")),
+		})
+	}
+}
 
 pub struct Compiler {
 	llvm: Context,
 	target: TargetMachine,
-	uid: unique_id::string::StringGenerator,
+	triple: TripleInfo,
+	options: CompilerOptions,
+	/// Counter for naming basic blocks; reset at the start of each function so output is deterministic
+	block_id: Cell<u32>,
+	/// Number of modules compiled so far, used to keep module-level symbols distinct when the modules are linked
+	module_id: Cell<u32>,
 }
 
 impl Compiler {
@@ -73,6 +220,17 @@ impl Compiler {
 	}
 
 	pub fn with_context(context: Context) -> Compiler {
+		Compiler::with_options(context, CompilerOptions::default())
+	}
+
+	/// # Panics
+	///
+	/// If LLVM can't generate code for the host. Use [`Compiler::try_with_options`] to handle this instead.
+	pub fn with_options(context: Context, options: CompilerOptions) -> Compiler {
+		Compiler::try_with_options(context, options).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	pub fn try_with_options(context: Context, options: CompilerOptions) -> Result<Compiler, TargetError> {
 		Target::initialize_all(&InitializationConfig {
 			asm_printer: true,
 			asm_parser: true,
@@ -81,13 +239,31 @@ impl Compiler {
 			info: true,
 			machine_code: true,
 		});
-		let triple = TargetMachine::get_default_triple();
-		let target = Target::from_triple(&triple).unwrap();
-		let machine = target.create_target_machine(&triple, "generic", &TargetMachine::get_host_cpu_features().to_string(), OptimizationLevel::None, RelocMode::Default, CodeModel::Default).unwrap();
-		Compiler {
+		// The host's CPU features only make sense when compiling for the host
+		let (triple, features) = match &options.target_triple {
+			Some(triple) => (TargetTriple::create(triple), String::new()),
+			None => (TargetMachine::get_default_triple(), TargetMachine::get_host_cpu_features().to_string()),
+		};
+		let triple_name = triple.as_str().to_string_lossy().into_owned();
+		let triple_info = TripleInfo::parse(&triple_name).ok_or_else(|| TargetError::MalformedTriple(triple_name.clone()))?;
+		let target = Target::from_triple(&triple).map_err(|e| TargetError::UnsupportedTarget(triple_name.clone(), e.to_string()))?;
+		let machine = target.create_target_machine(&triple, "generic", &features, OptimizationLevel::None, options.reloc_mode, options.code_model)
+			.ok_or_else(|| TargetError::MachineCreationFailed(triple_name, "generic".to_owned(), features.clone()))?;
+		Ok(Compiler {
 			llvm: context,
 			target: machine,
-			uid: unique_id::string::StringGenerator::default(),
+			triple: triple_info,
+			options,
+			block_id: Cell::new(0),
+			module_id: Cell::new(0),
+		})
+	}
+
+	/// The widths of the integer types that depend on the target, which LIR needs to check literals against
+	pub fn target_widths(&self) -> lir::TargetWidths {
+		lir::TargetWidths {
+			pointer: self.target.get_target_data().get_pointer_byte_size(None) * 8,
+			c_long: self.size_of(&lir::Type::Primitive(lir::Primitive::CLong)) as u32 * 8,
 		}
 	}
 
@@ -95,52 +271,95 @@ impl Compiler {
 		module.print_to_file(file_name).unwrap();
 	}
 
-	pub fn write_module(&self, module: &Module<'_>, file_name: impl AsRef<std::path::Path>) {
-		self.target.write_to_file(module, FileType::Object, file_name.as_ref()).unwrap();
+	pub fn ir_to_string(&self, module: &Module<'_>) -> String {
+		module.print_to_string().to_string()
+	}
+
+	pub fn write_module(&self, module: &Module<'_>, file_name: impl AsRef<std::path::Path>) -> Result<(), Diagnostic> {
+		self.target.write_to_file(module, FileType::Object, file_name.as_ref())
+			.map_err(|e| Diagnostic::error(format!("couldn't write object file {}: {}", file_name.as_ref().display(), e), None))
+	}
+
+	pub fn write_bitcode(&self, module: &Module<'_>, file_name: impl AsRef<std::path::Path>) -> Result<(), Diagnostic> {
+		// LLVM doesn't say why writing bitcode failed
+		if module.write_bitcode_to_path(file_name.as_ref()) {
+			Ok(())
+		} else {
+			Err(Diagnostic::error(format!("couldn't write bitcode to {}", file_name.as_ref().display()), None))
+		}
 	}
 	
 	pub fn compile_lir_module(&self, module: lir::Module) -> Module<'_> {
+		let module_id = self.module_id.get();
+		self.module_id.set(module_id + 1);
 		let llvm_module = self.llvm.create_module(&module.name.mod_mangle());
 		llvm_module.set_data_layout(&self.target.get_target_data().get_data_layout());
 		llvm_module.set_triple(&self.target.get_triple());
+		// Identifies which compiler produced an object, as clang does
+		let ident = self.llvm.metadata_node(&[self.llvm.metadata_string(&format!("lok version {}", env!("CARGO_PKG_VERSION"))).into()]);
+		llvm_module.add_global_metadata("llvm.ident", &ident);
 
 		let mut functions = HashMap::new();
 		for decl in module.fn_decls {
-			let (params, types) = decl.params.into_iter().map(|(s, ty)| (s, self.get_type(&ty))).unzip::<_, _, Vec<_>, Vec<_>>();
+			let external = decl.external;
+			let get_type = |ty: &lir::Type| if external { self.get_abi_type(ty) } else { self.get_type(ty) };
+			// The alignment of each parameter passed by pointer, which the callee may rely on
+			let by_pointer = decl.params.iter().map(|(_, ty)| if external && self.is_passed_by_pointer(ty) { Some(self.align_of(ty)) } else { None }).collect::<Vec<_>>();
+			let (params, types) = decl.params.into_iter()
+				.map(|(s, ty)| (s, if external && self.is_passed_by_pointer(&ty) { self.get_type(&ty).ptr_type(AddressSpace::Generic).into() } else { get_type(&ty) }))
+				.unzip::<_, _, Vec<_>, Vec<_>>();
 			let varadic = decl.varadic;
 			let function = llvm_module.add_function(
 				&decl.id.fn_mangle(),
-				decl.returns.map(|x| self.get_type(&x).fn_type(&types, varadic)).unwrap_or(self.llvm.void_type().fn_type(&types, false)),
+				decl.returns.map(|x| get_type(&x).fn_type(&types, varadic)).unwrap_or(self.llvm.void_type().fn_type(&types, varadic)),
 				Some(Linkage::External),
 			);
+			// Record the unmangled name, so symbols in the output can be traced back to the source
+			if !external {
+				function.add_attribute(AttributeLoc::Function, self.llvm.create_string_attribute("lok.name", &decl.id.source_name()));
+				self.add_function_attributes(function);
+				// Exported functions keep their unmangled name, and are the only ones meant to be visible
+				if self.options.hidden_visibility && !matches!(decl.id, lir::Ident::UnmangledItem(_)) {
+					function.as_global_value().set_visibility(GlobalVisibility::Hidden);
+				}
+			}
+			for (i, align) in by_pointer.into_iter().enumerate().filter_map(|(i, align)| align.map(|align| (i, align))) {
+				function.add_attribute(AttributeLoc::Param(i as u32), self.llvm.create_enum_attribute(Attribute::get_named_enum_kind_id("byval"), 0));
+				function.add_attribute(AttributeLoc::Param(i as u32), self.llvm.create_enum_attribute(Attribute::get_named_enum_kind_id("align"), align));
+			}
 			functions.insert(decl.id, (function, params));
 		}
 
 		let global_pool = GlobalPool {
-			strings: module.consts.strings.into_iter().enumerate().map(|(i, (v, null))| {
+			strings: module.consts.strings.into_iter().enumerate().map(|(i, (v, null, storage))| {
 				let value = self.llvm.const_string(&v, null);
-				let global = llvm_module.add_global(value.get_type(), Some(AddressSpace::Const), &format!("str{}", i));
+				let global = llvm_module.add_global(value.get_type(), Some(AddressSpace::Const), &format!("str{}.{}", module_id, i));
 				global.set_constant(true);
 				global.set_initializer(&value);
-				global
+				// Nothing outside the module refers to these by name, and only their contents matter, so identical
+				// strings can be merged
+				global.set_linkage(Linkage::Private);
+				global.set_unnamed_addr(true);
+				(global, storage)
 			}).collect(),
 		};
 
 		for def in module.fn_defs {
 			let (function, params) = functions.get(&def.id).expect("Was inserted in LIR stage").clone();
-			self.compile_fn_body(def.body, &params, &global_pool, &llvm_module, function);
+			self.compile_fn_body(def.body, &params, &def.mutable_params, &global_pool, &llvm_module, function);
 		}
 		
 		if let Some(def) = module.entry {
 			let function = llvm_module.add_function(
-				"main", //TODO
+				&self.options.entry_symbol,
 				match def.returns {
 					Some(ty) => self.get_type(&ty).fn_type(&[], false),
 					None => self.llvm.void_type().fn_type(&[], false),
 				},
 				Some(Linkage::External),
 			);
-			self.compile_fn_body(def.body, &[], &global_pool, &llvm_module, function);
+			self.add_function_attributes(function);
+			self.compile_fn_body(def.body, &[], &[], &global_pool, &llvm_module, function);
 		}
 		
 		llvm_module
@@ -151,23 +370,34 @@ impl Compiler {
 	fn get_type(&self, ty: &lir::Type) -> BasicTypeEnum<'_> {
 		match ty {
 			lir::Type::PtrConst(t) | lir::Type::PtrMut(t) => self.get_type(&t).ptr_type(AddressSpace::Generic).into(),
-			lir::Type::PtrDynConst(t) | lir::Type::PtrDynMut(t) => self.llvm.struct_type(&[
+			lir::Type::PtrDynConst(t) | lir::Type::PtrDynMut(t) | lir::Type::Slice(t) => self.llvm.struct_type(&[
 				self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None).into(),
 				self.get_type(&t).ptr_type(AddressSpace::Generic).into()
 			], false).into(),
-			lir::Type::Arr(..) => todo!(),
-			lir::Type::Slice(..) => todo!(),
-			lir::Type::Tuple(..) => todo!(),
+			lir::Type::Arr(t, n) => self.get_type(&t).array_type(*n as u32).into(),
+			lir::Type::Tuple(types) => self.llvm.struct_type(&types.iter().map(|t| self.get_type(t)).collect::<Vec<_>>(), false).into(),
+			lir::Type::Fn(params, returns, varadic) => {
+				let params = params.iter().map(|t| self.get_type(t)).collect::<Vec<_>>();
+				let fn_type = match returns {
+					Some(returns) => self.get_type(returns).fn_type(&params, *varadic),
+					None => self.llvm.void_type().fn_type(&params, *varadic),
+				};
+				fn_type.ptr_type(AddressSpace::Generic).into()
+			},
 			lir::Type::Primitive(p) => match p {
 				lir::Primitive::Bool => self.llvm.custom_width_int_type(1).into(),
 				lir::Primitive::I8 | lir::Primitive::U8 => self.llvm.i8_type().into(),
 				lir::Primitive::I16 | lir::Primitive::U16 => self.llvm.i16_type().into(),
 				lir::Primitive::I32 | lir::Primitive::U32 => self.llvm.i32_type().into(),
 				lir::Primitive::I64 | lir::Primitive::U64 => self.llvm.i64_type().into(),
+				lir::Primitive::I128 | lir::Primitive::U128 => self.llvm.i128_type().into(),
+				lir::Primitive::ISize | lir::Primitive::USize => self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None).into(),
+				lir::Primitive::F32 => self.llvm.f32_type().into(),
+				lir::Primitive::F64 => self.llvm.f64_type().into(),
 				lir::Primitive::CChar => self.llvm.i8_type().into(),
 				lir::Primitive::CShort => self.llvm.i16_type().into(), // ILP32, LLP64, LP64
 				lir::Primitive::CInt => self.llvm.i32_type().into(), // ILP32, LLP64, LP64
-				lir::Primitive::CLong => if self.target.get_triple().as_str().to_bytes().split(|&b| b == b'-').skip(2).next().unwrap() == b"windows" {
+				lir::Primitive::CLong => if self.triple.windows {
 					self.llvm.i32_type().into() // ILP32, LLP64 (Windows APIs)
 				} else {
 					self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None).into() // ILP32, LP64 (Unix APIs)
@@ -175,34 +405,91 @@ impl Compiler {
 				lir::Primitive::CLLong => self.llvm.i64_type().into(),
 			}
 			lir::Type::Name(..) => todo!(),
-			lir::Type::Never => todo!(),
+			lir::Type::Never => panic!("Never-typed values are never materialised"),
+		}
+	}
+
+	fn next_block_name(&self) -> String {
+		let id = self.block_id.get();
+		self.block_id.set(id + 1);
+		format!("bb{}", id)
+	}
+
+	/// The type used for `ty` when passing it across the C ABI, where it may differ from our own representation
+	fn get_abi_type(&self, ty: &lir::Type) -> BasicTypeEnum<'_> {
+		match ty {
+			lir::Type::Primitive(lir::Primitive::Bool) => self.llvm.i8_type().into(),
+			ty => self.get_type(ty),
+		}
+	}
+
+	/// The number of bytes between consecutive values of this type in memory, including padding
+	fn size_of(&self, ty: &lir::Type) -> u64 {
+		self.target.get_target_data().get_abi_size(&self.get_type(ty))
+	}
+
+	/// The ABI-required alignment of this type, in bytes
+	fn align_of(&self, ty: &lir::Type) -> u64 {
+		self.target.get_target_data().get_abi_alignment(&self.get_type(ty)) as u64
+	}
+
+	/// Whether a parameter of this type is passed to C functions in memory, as a pointer to a copy of the value.
+	///
+	/// This follows the SysV x86-64 rule that aggregates larger than two eightbytes are passed by hidden pointer. Other
+	/// targets' rules aren't implemented, so they get aggregates as plain values.
+	fn is_passed_by_pointer(&self, ty: &lir::Type) -> bool {
+		match ty {
+			lir::Type::Tuple(..) | lir::Type::Arr(..) if self.uses_sysv_x86_64_abi() => self.size_of(ty) > 16,
+			_ => false,
+		}
+	}
+
+	/// Whether C functions on the target follow the SysV x86-64 calling convention, as everything but Windows does there
+	fn uses_sysv_x86_64_abi(&self) -> bool {
+		self.triple.arch == "x86_64" && !self.triple.windows
+	}
+
+	/// Attributes requested in the options that apply to every function we generate code for
+	fn add_function_attributes(&self, function: FunctionValue<'_>) {
+		if self.options.frame_pointers {
+			function.add_attribute(AttributeLoc::Function, self.llvm.create_string_attribute("frame-pointer", "all"));
 		}
 	}
 
-	fn compile_fn_body<'ctx>(&'ctx self, body: lir::FnBody, param_decls: &[String], global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>) {
+	fn compile_fn_body<'ctx>(&'ctx self, body: lir::FnBody, param_decls: &[String], mutable_params: &[bool], global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>) {
+		self.block_id.set(0);
 		let builder = self.llvm.create_builder();
 		let basic_block = self.llvm.append_basic_block(fn_value, "decl");
 		builder.position_at_end(basic_block);
 
 		let param_values = fn_value.get_params();
 
-		let mut pointers = HashMap::<String, PointerValue<'ctx>>::new();
+		let mut locals = HashMap::<String, Local<'ctx>>::new();
+
+		// Parameters that can't be assigned to are used directly. Aggregates still get a copy in memory, since they're
+		// copied out of by pointer.
+		let needs_storage = |i: usize, value: &BasicValueEnum<'ctx>| mutable_params[i] || value.is_struct_value() || value.is_array_value();
 
-		for (param, value) in param_decls.into_iter().zip(param_values.iter()) {
-			pointers.insert(param.clone(), builder.build_alloca(value.get_type(), &param));
+		for (i, (param, value)) in param_decls.into_iter().zip(param_values.iter()).enumerate() {
+			if needs_storage(i, value) {
+				locals.insert(param.clone(), Local::Stack(builder.build_alloca(value.get_type(), &param)));
+			} else {
+				locals.insert(param.clone(), Local::Value(*value));
+			}
 		}
 
-		for decl in &body.decls {
+		for decl in body.decls.iter().filter(|decl| decl.ty != lir::Type::Never) {
 			let name = decl.name.local_mangle();
-			pointers.insert(name.clone(), builder.build_alloca(self.get_type(&decl.ty), &name));
+			locals.insert(name.clone(), Local::Stack(builder.build_alloca(self.get_type(&decl.ty), &name)));
 		}
 
 		for (param, value) in param_decls.into_iter().zip(param_values.into_iter()) {
-			let ptr = pointers.get(param).expect("Was inserted above");
-			builder.build_store(*ptr, value);
+			if let Local::Stack(ptr) = locals.get(param).expect("Was inserted above") {
+				builder.build_store(*ptr, value);
+			}
 		}
 
-		let block = self.compile_block(body.block, "entry", &pointers, &mut Vec::new(), global_pool, module, fn_value);
+		let block = self.compile_block(body.block, "entry", &locals, &mut Vec::new(), global_pool, module, fn_value);
 		builder.build_unconditional_branch(block.first_block);
 		match block.tail {
 			BlockTail::Returned => {} // Don't need to do anything; the last block is already terminated.
@@ -212,32 +499,62 @@ impl Compiler {
 			},
 			BlockTail::Value(v) => {
 				builder.position_at_end(block.last_block);
-				builder.build_return(Some(&v));
+				// A `()` tail in a function returning nothing
+				if fn_value.get_type().get_return_type().is_none() {
+					builder.build_return(None);
+				} else {
+					builder.build_return(Some(&v));
+				}
 			}
 		}
 	}
 
-	fn compile_block<'ctx>(&'ctx self, block: lir::Block, name: &str, pointers: &HashMap<String, PointerValue<'ctx>>, loops: &mut Vec<String>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>) -> BlockReturn<'ctx> {
+	fn compile_block<'ctx>(&'ctx self, block: lir::Block, name: &str, locals: &HashMap<String, Local<'ctx>>, loops: &mut Vec<String>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>) -> BlockReturn<'ctx> {
 		let builder = self.llvm.create_builder();
 		let mut working_block = self.llvm.append_basic_block(fn_value, name);
 		let first_block = working_block;
 		builder.position_at_end(working_block);
 
 		let mut breaks = vec![];
+		// Locals declared in this block, which go out of scope at its end
+		let mut live = vec![];
 
 		let mut returned = false;
 		for statement in block.statements {
 			match statement {
 				lir::Statement::Eval(expr) => {
-					self.compile_expr(expr.value, pointers, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+					let diverges = expr.diverges();
+					match expr.value {
+						// The value of an assignment used as a statement is thrown away, so don't produce it
+						lir::ExpressionValue::Assign(op, lhs, rhs) => {
+							self.compile_assign(op, lhs, *rhs, false, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+						},
+						value => {
+							self.compile_expr(lir::Expression { ty: expr.ty, value }, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+						},
+					}
+					if diverges {
+						self.seal_unreachable(&builder, working_block);
+						returned = true;
+						break;
+					}
 				},
 				lir::Statement::Return(expr) => {
 					returned = true;
 					match expr {
 						Some(expr) => {
-							let value = self.compile_expr(expr.value, pointers, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block).unwrap();
-							debug_assert_eq!(value.get_type(), fn_value.get_type().get_return_type().unwrap());
-							builder.build_return(Some(&value));
+							let value = self.compile_expr(expr, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+							// A function returning nothing can still return `()`, which is only evaluated for its side effects
+							match fn_value.get_type().get_return_type() {
+								Some(return_type) => {
+									let value = value.unwrap();
+									debug_assert_eq!(value.get_type(), return_type);
+									builder.build_return(Some(&value));
+								},
+								None => {
+									builder.build_return(None);
+								},
+							}
 							break;
 						},
 						None => {
@@ -247,32 +564,62 @@ impl Compiler {
 						}
 					}
 				},
-				lir::Statement::Break(expr) => {
+				lir::Statement::Break(label, expr) => {
 					returned = true;
-					let value = expr.and_then(|e| self.compile_expr(e.value, pointers, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block));
+					let value = expr.and_then(|e| self.compile_expr(e, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block));
 					breaks.push(BreakValue {
-					    label: None,
+					    label,
 					    value,
 					    block: working_block,
+					    continues: false,
+					});
+					break;
+				},
+				lir::Statement::Continue(label) => {
+					returned = true;
+					breaks.push(BreakValue {
+					    label,
+					    value: None,
+					    block: working_block,
+					    continues: true,
 					});
 					break;
 				},
 				lir::Statement::Decl(name, expr) => {
-					builder.build_store(
-						pointers.get(&name).expect("All decl statements are given pointers").clone(),
-						self.compile_expr(expr.value, pointers, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block).expect("Type was checked by LIR")
-					);
+					if expr.diverges() {
+						// The binding is never reached, so there is nothing to store
+						self.compile_expr(expr, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+						self.seal_unreachable(&builder, working_block);
+						returned = true;
+						break;
+					}
+					let value = self.compile_expr(expr, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block).expect("Type was checked by LIR");
+					let pointer = locals.get(&name).expect("All decl statements are given pointers").pointer();
+					if self.options.lifetime_markers {
+						self.build_lifetime_marker(&builder, module, "llvm.lifetime.start.p0i8", pointer);
+						live.push(pointer);
+					}
+					builder.build_store(pointer, value);
 				},
 			}
 		}
 
 		let tail = if returned {
 			BlockTail::Returned
+		} else if block.tail.as_ref().map(lir::Expression::diverges).unwrap_or(false) {
+			self.compile_expr(block.tail.unwrap(), locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block);
+			self.seal_unreachable(&builder, working_block);
+			BlockTail::Returned
 		} else {
-			match block.tail.and_then(|expr| self.compile_expr(expr.value, pointers, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block)) {
+			let tail = match block.tail.and_then(|expr| self.compile_expr(expr, locals, loops, &mut breaks, global_pool, module, fn_value, &builder, &mut working_block)) {
 				Some(val) => BlockTail::Value(val),
 				None => BlockTail::NoValue,
+			};
+			builder.position_at_end(working_block);
+			for pointer in live {
+				self.build_lifetime_marker(&builder, module, "llvm.lifetime.end.p0i8", pointer);
 			}
+			tail
 		};
 
 		BlockReturn {
@@ -283,18 +630,107 @@ impl Compiler {
 		}
 	}
 
-	fn compile_expr<'ctx>(&'ctx self, expr: lir::ExpressionValue, pointers: &HashMap<String, PointerValue<'ctx>>, loops: &mut Vec<String>, breaks: &mut Vec<BreakValue<'ctx>>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>, builder: &Builder<'ctx>, current_block: &mut BasicBlock<'ctx>) -> Option<BasicValueEnum<'ctx>> {
-		match expr {
+	/// Call `llvm.lifetime.start` or `llvm.lifetime.end` on a local's storage. Between an end and the next start its
+	/// stack slot can be shared with other locals.
+	fn build_lifetime_marker<'ctx>(&'ctx self, builder: &Builder<'ctx>, module: &Module<'ctx>, intrinsic: &str, pointer: PointerValue<'ctx>) {
+		let i8_ptr = self.llvm.i8_type().ptr_type(AddressSpace::Generic);
+		let function = module.get_function(intrinsic).unwrap_or_else(|| module.add_function(
+			intrinsic,
+			self.llvm.void_type().fn_type(&[self.llvm.i64_type().into(), i8_ptr.into()], false),
+			None,
+		));
+		let size = self.llvm.i64_type().const_int(self.target.get_target_data().get_abi_size(&pointer.get_type().get_element_type()), false);
+		let pointer = builder.build_bitcast(pointer, i8_ptr, "lifetimeptr");
+		builder.build_call(function, &[size.into(), pointer], "");
+	}
+
+	/// Extend or truncate an integer to the target's pointer width
+	fn cast_to_ptr_sized<'ctx>(&'ctx self, builder: &Builder<'ctx>, value: IntValue<'ctx>, signed: bool) -> IntValue<'ctx> {
+		let ptr_int = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None);
+		if value.get_type().get_bit_width() > ptr_int.get_bit_width() {
+			builder.build_int_truncate(value, ptr_int, "ptrsizetrunc")
+		} else if signed {
+			builder.build_int_s_extend_or_bit_cast(value, ptr_int, "ptrsizeext")
+		} else {
+			builder.build_int_z_extend_or_bit_cast(value, ptr_int, "ptrsizeext")
+		}
+	}
+
+	/// Terminate `block` with `unreachable` if control flow left it dangling after a diverging expression
+	fn seal_unreachable<'ctx>(&'ctx self, builder: &Builder<'ctx>, block: BasicBlock<'ctx>) {
+		if block.get_terminator().is_none() {
+			builder.position_at_end(block);
+			builder.build_unreachable();
+		}
+	}
+
+	/// Allocate a temporary in the function's `decl` block, with the locals, so it isn't allocated again on every pass
+	/// through a loop
+	fn build_temp_alloca<'ctx>(&'ctx self, fn_value: FunctionValue<'ctx>, ty: BasicTypeEnum<'ctx>, name: &str) -> PointerValue<'ctx> {
+		let builder = self.llvm.create_builder();
+		let decl_block = fn_value.get_first_basic_block().expect("The decl block is appended first");
+		match decl_block.get_terminator() {
+			Some(terminator) => builder.position_before(&terminator),
+			None => builder.position_at_end(decl_block),
+		}
+		builder.build_alloca(ty, name)
+	}
+
+	/// Compile an assignment. The assigned value is only produced if `needs_value` is set, since for an aggregate that
+	/// means loading all of it back out of memory.
+	fn compile_assign<'ctx>(&'ctx self, op: Option<lir::Op>, lhs: lir::LExpression, rhs: lir::Expression, needs_value: bool, locals: &HashMap<String, Local<'ctx>>, loops: &mut Vec<String>, breaks: &mut Vec<BreakValue<'ctx>>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>, builder: &Builder<'ctx>, current_block: &mut BasicBlock<'ctx>) -> Option<BasicValueEnum<'ctx>> {
+		// Aggregates are copied memory-to-memory instead of being loaded and stored as one value. The source may be the
+		// destination itself, as in `a = a` or `*p = *q`, so the copy has to allow overlap.
+		if let (None, lir::Type::Tuple(..)) | (None, lir::Type::Arr(..)) = (op, &lhs.ty) {
+			if let lir::ExpressionValue::LExpr(source) = rhs.value {
+				let src = self.compile_lexpr(source.value, locals, loops, breaks, global_pool, module, fn_value, builder, current_block);
+				let dest = self.compile_lexpr(lhs.value, locals, loops, breaks, global_pool, module, fn_value, builder, current_block);
+				let align = self.align_of(&lhs.ty) as u32;
+				let size = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None).const_int(self.size_of(&lhs.ty), false);
+				builder.build_memmove(dest, align, src, align, size).expect("Alignment is a power of two");
+				return if needs_value { Some(builder.build_load(dest, "assigntmp")) } else { None };
+			}
+		}
+		let val = match op {
+			Some(_) => todo!(),
+			None => self.compile_expr(rhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block),
+		};
+		builder.build_store(self.compile_lexpr(lhs.value, locals, loops, breaks, global_pool, module, fn_value, builder, current_block), val.expect("Type was checked by LIR"));
+		val
+	}
+
+	fn compile_expr<'ctx>(&'ctx self, expr: lir::Expression, locals: &HashMap<String, Local<'ctx>>, loops: &mut Vec<String>, breaks: &mut Vec<BreakValue<'ctx>>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>, builder: &Builder<'ctx>, current_block: &mut BasicBlock<'ctx>) -> Option<BasicValueEnum<'ctx>> {
+		match expr.value {
 			lir::ExpressionValue::Block(b) => {
-				let compiled_block = self.compile_block(*b, &self.uid.next_id(), pointers, loops, global_pool, module, fn_value);
+				let compiled_block = self.compile_block(*b, &self.next_block_name(), locals, loops, global_pool, module, fn_value);
 				builder.build_unconditional_branch(compiled_block.first_block);
-				*current_block = self.llvm.append_basic_block(fn_value, &self.uid.next_id());
 				breaks.extend(compiled_block.breaks);
+				if let BlockTail::Returned = compiled_block.tail {
+					// Control never falls out of the inner block, so there's no continuation to branch to. If the LIR
+					// knows this (the block is Never-typed), the enclosing block stops here too; otherwise carry on in a
+					// fresh block with no predecessors. A block ending in `break` or `continue` isn't terminated until the
+					// loop is finished, so it can't be continued in either.
+					if expr.ty == Some(lir::Type::Never) && compiled_block.last_block.get_terminator().is_some() {
+						*current_block = compiled_block.last_block;
+					} else {
+						*current_block = self.llvm.append_basic_block(fn_value, &self.next_block_name());
+					}
+					builder.position_at_end(*current_block);
+					return None;
+				}
+				// A block that stayed in one basic block can just be continued in, rather than branching out of it into
+				// yet another block
+				if compiled_block.first_block == compiled_block.last_block {
+					*current_block = compiled_block.last_block;
+					builder.position_at_end(*current_block);
+					return match compiled_block.tail {
+						BlockTail::Value(v) => Some(v),
+						_ => None,
+					};
+				}
+				*current_block = self.llvm.append_basic_block(fn_value, &self.next_block_name());
 				match compiled_block.tail {
-					BlockTail::Returned => {
-						builder.position_at_end(*current_block);
-						None
-					},
+					BlockTail::Returned => unreachable!(),
 					BlockTail::NoValue => {
 						builder.position_at_end(compiled_block.last_block);
 						builder.build_unconditional_branch(*current_block);
@@ -310,17 +746,17 @@ impl Compiler {
 				}
 			},
 		    lir::ExpressionValue::If(lir::If(cond, if_true, if_false)) => {
-				let comparison = self.compile_expr(cond.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block).unwrap().into_int_value();
-				let true_block = self.compile_block(*if_true, &self.uid.next_id(), pointers, loops, global_pool, module, fn_value);
-				let false_block = if_false.map(|b| self.compile_block(*b, &self.uid.next_id(), pointers, loops, global_pool, module, fn_value));
-				let next_block = self.llvm.append_basic_block(fn_value, &self.uid.next_id());
+				let comparison = self.compile_expr(*cond, locals, loops, breaks, global_pool, module, fn_value, builder, current_block).unwrap().into_int_value();
+				let true_block = self.compile_block(*if_true, &self.next_block_name(), locals, loops, global_pool, module, fn_value);
+				let false_block = if_false.map(|b| self.compile_block(*b, &self.next_block_name(), locals, loops, global_pool, module, fn_value));
+				let next_block = self.llvm.append_basic_block(fn_value, &self.next_block_name());
 				*current_block = next_block;
 				breaks.extend(true_block.breaks);
 				match false_block {
 					Some(false_block) => {
 						breaks.extend(false_block.breaks);
 						builder.build_conditional_branch(comparison, true_block.first_block, false_block.first_block);
-						match (true_block.tail, false_block.tail) {
+						let value = match (true_block.tail, false_block.tail) {
 							(BlockTail::Returned, BlockTail::Returned) => None,
 							(BlockTail::Returned, BlockTail::NoValue) => {
 								builder.position_at_end(false_block.last_block);
@@ -360,7 +796,10 @@ impl Compiler {
 								Some(phi.as_basic_value())
 							}
 							(BlockTail::NoValue, BlockTail::Value(_)) | (BlockTail::Value(_), BlockTail::NoValue) => panic!("Unexpected dissimilarity in if expression value"),
-						}
+						};
+						// Whatever uses the value is built after the branches rejoin, not at the end of one of them
+						builder.position_at_end(next_block);
+						value
 					},
 					None => {
 						builder.build_conditional_branch(comparison, true_block.first_block, next_block);
@@ -370,24 +809,85 @@ impl Compiler {
 								builder.position_at_end(true_block.last_block);
 								builder.build_unconditional_branch(next_block);
 							}
-							BlockTail::Value(_) => panic!("Unexpected value in single-branch if expression"),
+							BlockTail::Value(_) => panic!("Single-branch if expression with a value should have been rejected by LIR"),
 						}
 						builder.position_at_end(next_block);
 						None
 					}
 				}
 			},
-			lir::ExpressionValue::Loop(b) => {
-				let block = self.compile_block(*b, &self.uid.next_id(), pointers, loops, global_pool, module, fn_value);
-				builder.build_unconditional_branch(block.first_block);
-				builder.position_at_end(block.last_block);
+			lir::ExpressionValue::Match(scrutinee, cases, default) => {
+				let value = self.compile_expr(*scrutinee, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value();
+				let int_type = value.get_type();
+				let switch_block = *current_block;
+				let arms = cases.into_iter()
+					.map(|(case, block)| (Some(case), self.compile_block(block, &self.next_block_name(), locals, loops, global_pool, module, fn_value)))
+					.chain(std::iter::once((None, self.compile_block(*default, &self.next_block_name(), locals, loops, global_pool, module, fn_value))))
+					.collect::<Vec<_>>();
+				let next_block = self.llvm.append_basic_block(fn_value, &self.next_block_name());
+				*current_block = next_block;
+
+				builder.position_at_end(switch_block);
+				let mut switch_cases = vec![];
+				let mut default_block = None;
+				for (case, block) in &arms {
+					match case {
+						Some(case) if int_type.get_bit_width() > 64 => switch_cases.push((int_type.const_int_arbitrary_precision(&[*case as u64, (*case >> 64) as u64]), block.first_block)),
+						Some(case) => switch_cases.push((int_type.const_int(*case as u64, false), block.first_block)),
+						None => default_block = Some(block.first_block),
+					}
+				}
+				builder.build_switch(value, default_block.expect("Match always has a default arm"), &switch_cases);
+
+				let mut incoming = vec![];
+				for (_, block) in arms {
+					breaks.extend(block.breaks);
+					match block.tail {
+						BlockTail::Returned => {},
+						BlockTail::NoValue => {
+							builder.position_at_end(block.last_block);
+							builder.build_unconditional_branch(next_block);
+						},
+						BlockTail::Value(v) => {
+							builder.position_at_end(block.last_block);
+							builder.build_unconditional_branch(next_block);
+							incoming.push((v, block.last_block));
+						},
+					}
+				}
+				builder.position_at_end(next_block);
+				if incoming.is_empty() {
+					None
+				} else {
+					let phi = builder.build_phi(incoming[0].0.get_type(), "matchresolve");
+					for (value, block) in &incoming {
+						phi.add_incoming(&[(value, *block)]);
+					}
+					Some(phi.as_basic_value())
+				}
+			},
+			lir::ExpressionValue::Loop(label, b) => {
+				let block = self.compile_block(*b, &self.next_block_name(), locals, loops, global_pool, module, fn_value);
 				builder.build_unconditional_branch(block.first_block);
-				let (breaks_here, breaks_through) = block.breaks.into_iter().partition::<Vec<_>, _>(|b| b.label.is_none());
+				// A body that ends by leaving it has already been given its terminator, or will be below
+				if let BlockTail::NoValue | BlockTail::Value(_) = block.tail {
+					builder.position_at_end(block.last_block);
+					builder.build_unconditional_branch(block.first_block);
+				}
+				let (breaks_here, breaks_through) = block.breaks.into_iter().partition::<Vec<_>, _>(|b| b.label.is_none() || b.label == label);
 				breaks.extend(breaks_through);
+				let (continues, breaks_here) = breaks_here.into_iter().partition::<Vec<_>, _>(|b| b.continues);
+				for brk in continues {
+					builder.position_at_end(brk.block);
+					builder.build_unconditional_branch(block.first_block);
+				}
+				*current_block = self.llvm.append_basic_block(fn_value, &self.next_block_name());
 				if breaks_here.is_empty() {
+					// Nothing leaves the loop, so nothing reaches the block after it. It still has to exist for whatever
+					// follows to be compiled into, and is sealed as unreachable once the enclosing block sees the loop diverge.
+					builder.position_at_end(*current_block);
 					None
 				} else {
-					*current_block = self.llvm.append_basic_block(fn_value, &self.uid.next_id());
 					let values = breaks_here.into_iter().map(|brk| {
 						builder.position_at_end(brk.block);
 						builder.build_unconditional_branch(*current_block);
@@ -403,40 +903,255 @@ impl Compiler {
 					}
 				}
 			}
-		    lir::ExpressionValue::Assign(op, lhs, rhs) => {
-				let val = match op {
-					Some(_) => todo!(),
-					None => self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block),
-				};
-				builder.build_store(self.compile_lexpr(lhs.value, pointers, global_pool, module, fn_value, builder, current_block), val.expect("Type was checked by LIR"));
-				val
-			}
+		    lir::ExpressionValue::Assign(op, lhs, rhs) => self.compile_assign(op, lhs, *rhs, true, locals, loops, breaks, global_pool, module, fn_value, builder, current_block),
 			lir::ExpressionValue::Op(op, lhs, rhs) => {
-				match op {
-					lir::Op::Add => Some(BasicValueEnum::IntValue(builder.build_int_add(self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "addtmp"))),
-					lir::Op::Sub => Some(BasicValueEnum::IntValue(builder.build_int_sub(self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "subtmp"))),
-					lir::Op::Mul => Some(BasicValueEnum::IntValue(builder.build_int_mul(self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "multmp"))),
-					lir::Op::Div => Some(BasicValueEnum::IntValue(builder.build_int_signed_div(self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "divtmp"))),
-					lir::Op::Rem => Some(BasicValueEnum::IntValue(builder.build_int_signed_rem(self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "remtmp"))),
-					lir::Op::Eq => Some(BasicValueEnum::IntValue(builder.build_int_compare(IntPredicate::EQ, self.compile_expr(lhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), self.compile_expr(rhs.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value(), "eqtmp"))),
-					_ => todo!(),
+				// Operand signedness was unified in LIR, so either side's type decides how the bits are interpreted
+				let signed = match &lhs.ty {
+					Some(lir::Type::Primitive(p)) => p.is_signed(),
+					_ => true,
+				};
+				if lhs.ty.as_ref().map(lir::Type::is_thin_pointer).unwrap_or(false) {
+					let rhs_is_pointer = rhs.ty.as_ref().map(lir::Type::is_thin_pointer).unwrap_or(false);
+					let offset_signed = match &rhs.ty {
+						Some(lir::Type::Primitive(p)) => p.is_signed(),
+						_ => true,
+					};
+					let lhs = self.compile_expr(*lhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_pointer_value();
+					let rhs = self.compile_expr(*rhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?;
+					if rhs_is_pointer {
+						let diff = builder.build_ptr_diff(lhs, rhs.into_pointer_value(), "ptrdifftmp");
+						return Some(BasicValueEnum::IntValue(self.cast_to_ptr_sized(builder, diff, true)));
+					}
+					// GEP indices are pointer-sized
+					let offset = self.cast_to_ptr_sized(builder, rhs.into_int_value(), offset_signed);
+					let offset = match op {
+						lir::Op::Sub => builder.build_int_neg(offset, "negtmp"),
+						_ => offset,
+					};
+					// SAFETY: Lok pointer arithmetic has the same out-of-bounds caveats as C's
+					return Some(BasicValueEnum::PointerValue(unsafe { builder.build_gep(lhs, &[offset], "ptraddtmp") }));
+				}
+				if let Some(lir::Type::Primitive(p)) = &lhs.ty {
+					if p.is_float() {
+						let lhs = self.compile_expr(*lhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_float_value();
+						let rhs = self.compile_expr(*rhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_float_value();
+						// Comparisons are ordered: any comparison involving NaN is false
+						return Some(match op {
+							lir::Op::Add => builder.build_float_add(lhs, rhs, "addtmp").into(),
+							lir::Op::Sub => builder.build_float_sub(lhs, rhs, "subtmp").into(),
+							lir::Op::Mul => builder.build_float_mul(lhs, rhs, "multmp").into(),
+							lir::Op::Div => builder.build_float_div(lhs, rhs, "divtmp").into(),
+							lir::Op::Rem => builder.build_float_rem(lhs, rhs, "remtmp").into(),
+							lir::Op::Eq => builder.build_float_compare(FloatPredicate::OEQ, lhs, rhs, "eqtmp").into(),
+							lir::Op::Lt => builder.build_float_compare(FloatPredicate::OLT, lhs, rhs, "lttmp").into(),
+							lir::Op::Le => builder.build_float_compare(FloatPredicate::OLE, lhs, rhs, "letmp").into(),
+							lir::Op::Ge => builder.build_float_compare(FloatPredicate::OGE, lhs, rhs, "getmp").into(),
+							lir::Op::Gt => builder.build_float_compare(FloatPredicate::OGT, lhs, rhs, "gttmp").into(),
+						});
+					}
 				}
+				let lhs = self.compile_expr(*lhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value();
+				let rhs = self.compile_expr(*rhs, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value();
+				Some(BasicValueEnum::IntValue(match op {
+					lir::Op::Add => builder.build_int_add(lhs, rhs, "addtmp"),
+					lir::Op::Sub => builder.build_int_sub(lhs, rhs, "subtmp"),
+					lir::Op::Mul => builder.build_int_mul(lhs, rhs, "multmp"),
+					lir::Op::Div if signed => builder.build_int_signed_div(lhs, rhs, "divtmp"),
+					lir::Op::Div => builder.build_int_unsigned_div(lhs, rhs, "divtmp"),
+					lir::Op::Rem if signed => builder.build_int_signed_rem(lhs, rhs, "remtmp"),
+					lir::Op::Rem => builder.build_int_unsigned_rem(lhs, rhs, "remtmp"),
+					lir::Op::Eq => builder.build_int_compare(IntPredicate::EQ, lhs, rhs, "eqtmp"),
+					lir::Op::Lt => builder.build_int_compare(if signed { IntPredicate::SLT } else { IntPredicate::ULT }, lhs, rhs, "lttmp"),
+					lir::Op::Le => builder.build_int_compare(if signed { IntPredicate::SLE } else { IntPredicate::ULE }, lhs, rhs, "letmp"),
+					lir::Op::Ge => builder.build_int_compare(if signed { IntPredicate::SGE } else { IntPredicate::UGE }, lhs, rhs, "getmp"),
+					lir::Op::Gt => builder.build_int_compare(if signed { IntPredicate::SGT } else { IntPredicate::UGT }, lhs, rhs, "gttmp"),
+				}))
 			}
+			lir::ExpressionValue::FnRef(id) => {
+				let function = module.get_function(&id.fn_mangle()).expect("Undefined reference to function");
+				Some(function.as_global_value().as_pointer_value().into())
+			},
 			lir::ExpressionValue::CallConcrete(id, args) => {
 				let callee = module.get_function(&id.fn_mangle()).expect("Undefined reference to function");
-				let arguments = args.into_iter().map(|expr| self.compile_expr(expr.value, pointers, loops, breaks, global_pool, module, fn_value, builder, current_block)).collect::<Option<Vec<_>>>()?;
-				builder.build_call(callee, &arguments, "calltmp").try_as_basic_value().left()
+				let param_types = callee.get_type().get_param_types();
+				let signed = args.iter().map(|arg| match &arg.ty {
+					Some(lir::Type::Primitive(p)) => p.is_signed(),
+					_ => true,
+				}).collect::<Vec<_>>();
+				let arguments = args.into_iter()
+					.map(|expr| self.compile_expr(expr, locals, loops, breaks, global_pool, module, fn_value, builder, current_block))
+					.collect::<Option<Vec<_>>>()?
+					.into_iter()
+					.enumerate()
+					.map(|(i, arg)| match (arg, param_types.get(i)) {
+						// Aggregates passed in memory go through a temporary copy
+						(BasicValueEnum::StructValue(_), Some(BasicTypeEnum::PointerType(_))) | (BasicValueEnum::ArrayValue(_), Some(BasicTypeEnum::PointerType(_))) => {
+							let temp = self.build_temp_alloca(fn_value, arg.get_type(), "byvaltmp");
+							builder.build_store(temp, arg);
+							temp.into()
+						},
+						// C passes _Bool as a byte, but we keep booleans as i1
+						(BasicValueEnum::IntValue(v), Some(BasicTypeEnum::IntType(t))) if v.get_type().get_bit_width() == 1 && t.get_bit_width() == 8 => {
+							builder.build_int_z_extend(v, *t, "boolarg").into()
+						},
+						// Variadic arguments undergo C's default argument promotions
+						(BasicValueEnum::IntValue(v), None) if v.get_type().get_bit_width() < 32 => if signed[i] {
+							builder.build_int_s_extend(v, self.llvm.i32_type(), "vapromote").into()
+						} else {
+							builder.build_int_z_extend(v, self.llvm.i32_type(), "vapromote").into()
+						},
+						(BasicValueEnum::FloatValue(v), None) if v.get_type() == self.llvm.f32_type() => {
+							builder.build_float_ext(v, self.llvm.f64_type(), "vapromote").into()
+						},
+						(arg, _) => arg,
+					})
+					.collect::<Vec<_>>();
+				let result = builder.build_call(callee, &arguments, "calltmp").try_as_basic_value().left();
+				match (result, &expr.ty) {
+					(Some(BasicValueEnum::IntValue(v)), Some(lir::Type::Primitive(lir::Primitive::Bool))) if v.get_type().get_bit_width() != 1 => {
+						Some(builder.build_int_truncate(v, self.llvm.bool_type(), "boolret").into())
+					},
+					// Functions returning `()` are void in LLVM
+					(None, Some(ty)) if ty.is_unit() => Some(self.llvm.const_struct(&[], false).into()),
+					(result, _) => result,
+				}
+			},
+			lir::ExpressionValue::Not(e) => {
+				let value = self.compile_expr(*e, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value();
+				Some(builder.build_not(value, "nottmp").into())
+			},
+			lir::ExpressionValue::Cast(e) => {
+				let signed = match &e.ty {
+					Some(lir::Type::Primitive(p)) => p.is_signed(),
+					_ => false,
+				};
+				let value = self.compile_expr(*e, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?;
+				let ty = self.get_type(expr.ty.as_ref().expect("Casts are always typed"));
+				Some(match (value, ty) {
+					// Only zero is false
+					(BasicValueEnum::IntValue(v), BasicTypeEnum::IntType(t)) if t.get_bit_width() == 1 && v.get_type().get_bit_width() != 1 => {
+						builder.build_int_compare(IntPredicate::NE, v, v.get_type().const_zero(), "casttmp").into()
+					},
+					// The C integer types have target-dependent widths, so whether a conversion is needed is decided by the
+					// widths they lower to rather than by which types they are
+					(BasicValueEnum::IntValue(v), BasicTypeEnum::IntType(t)) => {
+						let (from, to) = (v.get_type().get_bit_width(), t.get_bit_width());
+						if from == to {
+							v.into()
+						} else if from > to {
+							builder.build_int_truncate(v, t, "casttmp").into()
+						} else if signed {
+							builder.build_int_s_extend(v, t, "casttmp").into()
+						} else {
+							builder.build_int_z_extend(v, t, "casttmp").into()
+						}
+					},
+					(BasicValueEnum::PointerValue(p), BasicTypeEnum::PointerType(t)) => builder.build_pointer_cast(p, t, "casttmp").into(),
+					_ => unreachable!("Cast was checked by LIR"),
+				})
+			},
+			lir::ExpressionValue::MakeDyn(pointer, metadata) => {
+				let ty = self.get_type(expr.ty.as_ref().expect("Fat pointers are always typed")).into_struct_type();
+				let signed = match &metadata.ty {
+					Some(lir::Type::Primitive(p)) => p.is_signed(),
+					_ => true,
+				};
+				let pointer = self.compile_expr(*pointer, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?;
+				let metadata = self.compile_expr(*metadata, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_int_value();
+				let metadata = self.cast_to_ptr_sized(builder, metadata, signed);
+				let fat = builder.build_insert_value(ty.get_undef(), metadata, 0, "dynmeta").unwrap();
+				let fat = builder.build_insert_value(fat, pointer, 1, "dynptr").unwrap();
+				Some(fat.into_struct_value().into())
+			},
+			lir::ExpressionValue::ArrToSlice(array) => {
+				let ty = self.get_type(expr.ty.as_ref().expect("Slices are always typed")).into_struct_type();
+				let len = match &array.ty {
+					Some(lir::Type::Arr(_, n)) => *n,
+					_ => panic!("Only arrays are converted to slices"),
+				};
+				// The elements are pointed to where they already are, or from a temporary if the array isn't stored
+				let array = match array.value {
+					lir::ExpressionValue::LExpr(lexpr) => self.compile_lexpr(lexpr.value, locals, loops, breaks, global_pool, module, fn_value, builder, current_block),
+					value => {
+						let value = self.compile_expr(lir::Expression { ty: array.ty, value }, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?;
+						let temp = self.build_temp_alloca(fn_value, value.get_type(), "slicetmp");
+						builder.build_store(temp, value);
+						temp
+					},
+				};
+				let zero = self.llvm.i32_type().const_zero();
+				let data = unsafe { builder.build_in_bounds_gep(array, &[zero, zero], "slicedata") };
+				let len = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None).const_int(len, false);
+				let slice = builder.build_insert_value(ty.get_undef(), len, 0, "slicelen").unwrap();
+				let slice = builder.build_insert_value(slice, data, 1, "slice").unwrap();
+				Some(slice.into_struct_value().into())
+			},
+			lir::ExpressionValue::ConstFloat(val) => {
+				let ty = self.get_type(expr.ty.as_ref().expect("Float constants are always typed")).into_float_type();
+				Some(ty.const_float(val).into())
+			},
+			lir::ExpressionValue::SizeOf(ty) => {
+				let size_type = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None);
+				Some(size_type.const_int(self.size_of(&ty), false).into())
+			},
+			lir::ExpressionValue::AlignOf(ty) => {
+				let size_type = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None);
+				Some(size_type.const_int(self.align_of(&ty), false).into())
+			},
+			lir::ExpressionValue::TupleField(tuple, i) => {
+				let tuple = self.compile_expr(*tuple, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)?.into_struct_value();
+				builder.build_extract_value(tuple, i, "fieldtmp")
+			},
+			lir::ExpressionValue::ConstInt(val) => {
+				let ty = self.get_type(expr.ty.as_ref().expect("Integer constants are always typed")).into_int_type();
+				if ty.get_bit_width() > 64 {
+					Some(BasicValueEnum::IntValue(ty.const_int_arbitrary_precision(&[val as u64, (val >> 64) as u64])))
+				} else {
+					Some(BasicValueEnum::IntValue(ty.const_int(val as u64, true)))
+				}
+			},
+			lir::ExpressionValue::Unit => Some(self.llvm.const_struct(&[], false).into()),
+			lir::ExpressionValue::ConstStr(i) => match global_pool.strings[i] {
+				(global, lir::StringStorage::Static) => Some(BasicValueEnum::PointerValue(global.as_pointer_value())), //TODO: Caching?
+				(global, lir::StringStorage::Heap) => {
+					let size_type = self.llvm.ptr_sized_int_type(&self.target.get_target_data(), None);
+					let malloc = module.get_function("malloc").unwrap_or_else(|| module.add_function(
+						"malloc",
+						self.llvm.i8_type().ptr_type(AddressSpace::Generic).fn_type(&[size_type.into()], false),
+						Some(Linkage::External),
+					));
+					let len = global.as_pointer_value().get_type().get_element_type().into_array_type().len();
+					let size = size_type.const_int(len as u64, false);
+					let heap = builder.build_call(malloc, &[size.into()], "heapstr").try_as_basic_value().left().expect("malloc returns a pointer").into_pointer_value();
+					builder.build_memcpy(heap, 1, global.as_pointer_value(), 1, size).expect("Alignment is a power of two");
+					Some(heap.into())
+				},
+			},
+			lir::ExpressionValue::ConstBytes(i, _) => {
+				let (global, _) = global_pool.strings[i];
+				Some(match expr.ty {
+					Some(lir::Type::Arr(..)) => global.get_initializer().expect("String globals are initialized"),
+					_ => global.as_pointer_value().into(),
+				})
+			},
+			lir::ExpressionValue::LExpr(lexpr) => {
+				if let lir::LExpressionValue::Var(ident @ lir::Ident::Local(_)) = &lexpr.value {
+					if let Some(Local::Value(value)) = locals.get(&ident.local_mangle()) {
+						return Some(*value);
+					}
+				}
+				Some(builder.build_load(self.compile_lexpr(lexpr.value, locals, loops, breaks, global_pool, module, fn_value, builder, current_block), "loadtmp"))
 			},
-			lir::ExpressionValue::ConstInt(val) => Some(BasicValueEnum::IntValue(self.llvm.i32_type().const_int(val as u64, true))),
-			lir::ExpressionValue::ConstStr(i) => Some(BasicValueEnum::PointerValue(global_pool.strings[i].as_pointer_value())), //TODO: Caching?
-			lir::ExpressionValue::LExpr(lexpr) => Some(builder.build_load(self.compile_lexpr(lexpr.value, pointers, global_pool, module, fn_value, builder, current_block), "loadtmp")),
 		}
 	}
 
-	fn compile_lexpr<'ctx>(&'ctx self, expr: lir::LExpressionValue, pointers: &HashMap<String, PointerValue<'ctx>>, _global_pool: &GlobalPool<'ctx>, _module: &Module<'ctx>, _fn_value: FunctionValue<'ctx>, _builder: &Builder<'ctx>, _current_block: &mut BasicBlock<'ctx>) -> PointerValue<'ctx> {
+	fn compile_lexpr<'ctx>(&'ctx self, expr: lir::LExpressionValue, locals: &HashMap<String, Local<'ctx>>, loops: &mut Vec<String>, breaks: &mut Vec<BreakValue<'ctx>>, global_pool: &GlobalPool<'ctx>, module: &Module<'ctx>, fn_value: FunctionValue<'ctx>, builder: &Builder<'ctx>, current_block: &mut BasicBlock<'ctx>) -> PointerValue<'ctx> {
 		match expr {
+			lir::LExpressionValue::Deref(pointer) => self.compile_expr(*pointer, locals, loops, breaks, global_pool, module, fn_value, builder, current_block)
+				.expect("Type was checked by LIR")
+				.into_pointer_value(),
 			lir::LExpressionValue::Var(ident) => match ident {
-				lir::Ident::Local(_) => pointers.get(&ident.local_mangle()).expect("Local variable should have been declared").clone(),
+				lir::Ident::Local(_) => locals.get(&ident.local_mangle()).expect("Local variable should have been declared").pointer(),
 				_ => todo!(),
 			}
 		}
@@ -460,8 +1175,26 @@ struct BreakValue<'ctx> {
 	label: Option<String>, // If None, breaks from the first loop encountered
 	value: Option<BasicValueEnum<'ctx>>,
 	block: BasicBlock<'ctx>,
+	continues: bool, // Whether this goes back to the start of the loop instead of leaving it
+}
+
+/// Where the value of a local variable lives
+#[derive(Clone, Copy)]
+enum Local<'ctx> {
+	Stack(PointerValue<'ctx>),
+	/// A parameter that is never assigned to, so doesn't need to be stored anywhere
+	Value(BasicValueEnum<'ctx>),
+}
+
+impl<'ctx> Local<'ctx> {
+	fn pointer(&self) -> PointerValue<'ctx> {
+		match self {
+			Local::Stack(ptr) => *ptr,
+			Local::Value(_) => panic!("Attempted to take the address of a parameter without storage"),
+		}
+	}
 }
 
 struct GlobalPool<'ctx> {
-	strings: Vec<GlobalValue<'ctx>>,
+	strings: Vec<(GlobalValue<'ctx>, lir::StringStorage)>,
 }
\ No newline at end of file
@@ -35,6 +35,8 @@ pub enum Def {
 #[derive(Debug)]
 pub struct FnExtern {
 	pub name: Ident,
+	/// Path the function can also be called by, e.g. `c` in `extern fn c::puts(...)`
+	pub namespace: Vec<Ident>,
 	pub params: Vec<(Option<Ident>, Type)>,
 	pub varadic: bool,
 	pub returns: Option<Type>,
@@ -43,7 +45,10 @@ pub struct FnExtern {
 #[derive(Debug)]
 pub struct FnDef {
 	pub name: Ident,
-	pub params: Vec<(Ident, Type)>,
+	/// Exported functions keep their name as their symbol, so they can be called from other languages
+	pub export: bool,
+	/// Name, type, and whether the parameter can be assigned to in the body
+	pub params: Vec<(Ident, Type, bool)>,
 	pub returns: Option<Type>,
 	pub body: Block,
 }
@@ -66,10 +71,14 @@ pub enum Statement {
 		name: Ident,
 		mutable: bool,
 		expected_type: Option<Type>,
-		value: Expression,
+		value: Option<Expression>,
+		span: (usize, usize),
 	},
 	Expression(Expression),
-	Break(Option<Expression>),
+	/// The label of the loop to break out of, if not the innermost one, the loop's value, and the span of the `break`
+	Break(Option<Ident>, Option<Expression>, (usize, usize)),
+	/// The label of the loop to start the next iteration of, if not the innermost one
+	Continue(Option<Ident>),
 	Return(Option<Expression>),
 }
 
@@ -81,23 +90,65 @@ pub struct If(pub Box<Expression>, pub Box<Block>, pub Option<Either<Box<If>, Bo
 #[derive(Debug)]
 pub enum Expression {
 	If(If),
-	Loop(Box<Block>),
+	/// `'label: loop { ... }`, with an optional label
+	Loop(Option<Ident>, Box<Block>),
 	Block(Box<Block>),
+	/// Arms are an integer pattern with its suffix, or `None` for `_`, and the arm's value
+	Match(Box<Expression>, Vec<(Option<lir::IntLiteral>, Expression)>),
 
 	Assign(Box<Expression>, Option<Op>, Box<Expression>),
 
 	Op(Op, Box<Expression>, Box<Expression>),
-
-	Call(Box<Expression>, Vec<Expression>),
-
-	LVar(NSIdent),
-	Int(u64),
+	/// `!`, on booleans
+	Not(Box<Expression>),
+	/// `~`, on integers
+	BitNot(Box<Expression>),
+	/// `e as T`
+	Cast(Box<Expression>, Type),
+
+	/// The function, its arguments, and the span of the whole call
+	Call(Box<Expression>, Vec<Expression>, (usize, usize)),
+	/// Field or tuple element access, `a.b` or `a.0`
+	Field(Box<Expression>, Ident),
+	/// `dyn(pointer, metadata)`, building a fat pointer
+	MakeDyn(Box<Expression>, Box<Expression>),
+	/// `sizeof T`, the number of bytes between consecutive `T`s in memory
+	SizeOf(Type),
+	/// `alignof T`
+	AlignOf(Type),
+
+	/// A name, and its span
+	LVar(NSIdent, (usize, usize)),
+	Deref(Box<Expression>),
+	/// Value and optional type suffix
+	Int(lir::IntLiteral),
+	Float(f64),
+	Unit,
 	CStringRef(Vec<u8>),
+	/// `h"..."`, a Lok string copied into a fresh heap allocation
+	LokStringHeap(Vec<u8>),
+	/// `ch"..."`, a C string copied into a fresh heap allocation
+	CStringHeap(Vec<u8>),
+	/// `b"..."`, exactly the bytes written with no terminator
+	ByteStringRef(Vec<u8>),
+	/// `'c'`, as a code point
+	Char(u32),
+}
+
+impl Expression {
+	/// Where the expression is in the source, for the kinds of expression that record it
+	pub fn span(&self) -> Option<(usize, usize)> {
+		match self {
+			Expression::Call(_, _, span) | Expression::LVar(_, span) => Some(*span),
+			_ => None,
+		}
+	}
 }
 
 #[derive(Debug, Clone)]
 pub enum Type {
-	Name(NSIdent),
+	/// A name, and its span
+	Name(NSIdent, (usize, usize)),
 	PtrConst(Box<Type>),
 	PtrMut(Box<Type>),
 	PtrDynConst(Box<Type>),
@@ -105,6 +156,8 @@ pub enum Type {
 	Slice(Box<Type>),
 	Arr(Box<Type>, u64),
 	Tuple(Vec<Type>),
+	/// Pointer to a function with the given parameters and return type, which may be variadic
+	Fn(Vec<Type>, Option<Box<Type>>, bool),
 }
 
 pub type NSIdent = Vec<Ident>;
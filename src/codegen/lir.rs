@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
 use either::{Either, Left, Right};
 
-use crate::error::{LIRError, LIRErrorType};
+use crate::error::{Diagnostic, LIRError, LIRErrorType};
 
 use super::ast;
-use super::{NameResolveMap, StackScope};
+use super::{InitState, NameResolveMap, StackScope};
 
 #[derive(Debug)]
 pub struct Module {
@@ -12,11 +15,22 @@ pub struct Module {
 	pub fn_defs: Vec<DefFn>,
 	pub consts: Constants,
 	pub entry: Option<DefEntry>,
+	/// Problems found while lowering that don't stop the module from being compiled
+	pub warnings: Vec<Diagnostic>,
 }
 
 #[derive(Debug)]
 pub struct Constants {
-	pub strings: Vec<(Vec<u8>, bool)>,
+	/// Contents, whether a null terminator is added, and where the string lives at runtime
+	pub strings: Vec<(Vec<u8>, bool, StringStorage)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringStorage {
+	/// A constant global
+	Static,
+	/// A copy of the constant in memory from `malloc`, made each time the literal is evaluated
+	Heap,
 }
 
 #[derive(Clone, Debug)]
@@ -25,12 +39,16 @@ pub struct DeclFn {
 	pub params: Vec<(String, Type)>,
 	pub varadic: bool,
 	pub returns: Option<Type>,
+	/// Whether this function is defined outside of Lok, and so must be called with the C ABI
+	pub external: bool,
 }
 
 #[derive(Debug)]
 pub struct DefFn {
 	pub id: Ident,
 	pub body: FnBody,
+	/// Whether each parameter was declared `mut`
+	pub mutable_params: Vec<bool>,
 }
 
 #[derive(Debug)]
@@ -54,8 +72,12 @@ pub struct Block {
 #[derive(Clone, Debug)]
 pub enum Statement {
 	Decl(String, Expression),
+	/// Evaluates an expression of any type for its side effects, discarding the value
 	Eval(Expression),
-	Break(Option<Expression>),
+	/// The label of the loop to break out of, or `None` for the innermost one
+	Break(Option<String>, Option<Expression>),
+	/// The label of the loop to continue, or `None` for the innermost one
+	Continue(Option<String>),
 	Return(Option<Expression>),
 }
 
@@ -92,23 +114,46 @@ pub struct If(pub Box<Expression>, pub Box<Block>, pub Option<Box<Block>>);
 #[derive(Clone, Debug)]
 pub enum ExpressionValue {
 	If(If),
-	Loop(Box<Block>),
+	/// A loop, and its label if it has one
+	Loop(Option<String>, Box<Block>),
 	Block(Box<Block>),
+	/// Scrutinee, the arms for specific values (as two's complement bit patterns), and the default arm
+	Match(Box<Expression>, Vec<(u128, Block)>, Box<Block>),
 
 	Assign(Option<Op>, LExpression, Box<Expression>),
 
 	Op(Op, Box<Expression>, Box<Expression>),
+	/// Logical not on booleans, or bitwise not on integers; both flip every bit
+	Not(Box<Expression>),
+	/// Conversion to the type of the cast expression
+	Cast(Box<Expression>),
 	
 	CallConcrete(Ident, Vec<Expression>),
+	/// The address of a function
+	FnRef(Ident),
+	TupleField(Box<Expression>, u32),
+	/// Build a fat pointer from a thin pointer and a metadata word
+	MakeDyn(Box<Expression>, Box<Expression>),
+	/// View an array as a slice of all of its elements
+	ArrToSlice(Box<Expression>),
+	/// The size or alignment of a type, which are only known once the target is
+	SizeOf(Type),
+	AlignOf(Type),
 
 	LExpr(LExpression),
-	ConstInt(u64),
+	ConstInt(u128),
+	ConstFloat(f64),
 	ConstStr(usize /* Index into global string pool */),
+	/// A byte string, as an index into the global string pool and its length. It's a pointer to the bytes unless it has
+	/// been coerced to an array of them.
+	ConstBytes(usize, u64),
+	Unit,
 }
 
 #[derive(Clone, Debug)]
 pub enum LExpressionValue {
 	Var(Ident),
+	Deref(Box<Expression>),
 }
 
 #[derive(Clone, Debug)]
@@ -125,11 +170,16 @@ pub enum Type {
 	Name(Ident),
 	PtrConst(Box<Type>),
 	PtrMut(Box<Type>),
+	/// Fat pointers: a pointer paired with a pointer-sized metadata word, such as the number of elements pointed to.
+	/// Field `0` is the metadata and field `1` the plain pointer.
 	PtrDynConst(Box<Type>),
 	PtrDynMut(Box<Type>),
+	/// Laid out like a fat pointer: the number of elements, then a pointer to the first
 	Slice(Box<Type>),
 	Arr(Box<Type>, u64),
 	Tuple(Vec<Type>),
+	/// Pointer to a function with the given parameters and return type, which may be variadic
+	Fn(Vec<Type>, Option<Box<Type>>, bool),
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -139,10 +189,17 @@ pub enum Primitive {
 	I16,
 	I32,
 	I64,
+	I128,
 	U8,
 	U16,
 	U32,
 	U64,
+	U128,
+	/// Pointer-sized integers
+	ISize,
+	USize,
+	F32,
+	F64,
 	CChar,
 	CShort,
 	CInt,
@@ -150,46 +207,177 @@ pub enum Primitive {
 	CLLong,
 }
 
+impl Type {
+	/// Whether this is `()`, the empty tuple
+	pub fn is_unit(&self) -> bool {
+		*self == Type::Tuple(vec![])
+	}
+
+	/// Whether this is a plain pointer, as opposed to a fat `dyn` pointer
+	pub fn is_thin_pointer(&self) -> bool {
+		match self {
+			Type::PtrConst(_) | Type::PtrMut(_) => true,
+			_ => false,
+		}
+	}
+}
+
+impl Primitive {
+	/// Whether arithmetic and ordering can be performed on this type
+	pub fn is_numeric(&self) -> bool {
+		*self != Primitive::Bool
+	}
+
+	pub fn is_float(&self) -> bool {
+		match self {
+			Primitive::F32 | Primitive::F64 => true,
+			_ => false,
+		}
+	}
+
+	/// Whether values of this type are interpreted as two's complement signed integers
+	pub fn is_signed(&self) -> bool {
+		match self {
+			Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::I64 | Primitive::I128 | Primitive::ISize => true,
+			Primitive::F32 | Primitive::F64 => true,
+			Primitive::CChar | Primitive::CShort | Primitive::CInt | Primitive::CLong | Primitive::CLLong => true,
+			Primitive::Bool | Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64 | Primitive::U128 | Primitive::USize => false,
+		}
+	}
+
+	/// Whether an integer literal with this value can be given this type on the target
+	fn can_hold(&self, value: i128, target: &TargetWidths) -> bool {
+		let (bits, signed) = match self {
+			Primitive::I8 => (8, true),
+			Primitive::I16 => (16, true),
+			Primitive::I32 => (32, true),
+			Primitive::I64 => (64, true),
+			Primitive::I128 => (128, true),
+			Primitive::U8 => (8, false),
+			Primitive::U16 => (16, false),
+			Primitive::U32 => (32, false),
+			Primitive::U64 => (64, false),
+			Primitive::U128 => (128, false),
+			Primitive::ISize => (target.pointer, true),
+			Primitive::USize => (target.pointer, false),
+			Primitive::CChar => (8, true),
+			Primitive::CShort => (16, true),
+			Primitive::CInt => (32, true),
+			Primitive::CLong => (target.c_long, true),
+			Primitive::CLLong => (64, true),
+			_ => return false,
+		};
+		match (signed, bits) {
+			(true, 128) => true,
+			(true, bits) => value >= -(1 << (bits - 1)) && value < 1 << (bits - 1),
+			(false, 128) => value >= 0,
+			(false, bits) => value >= 0 && value < 1 << bits,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Ident {
 	Local(String),
 	UnmangledItem(String),
-	// Function(Vec<String>),
+	Function(Vec<String>),
 	// Static(Vec<String>),
 	// Type(Vec<String>),
 }
 
+impl DeclFn {
+	/// Whether two declarations describe the same function type. Parameter names don't matter.
+	pub fn same_signature(&self, other: &DeclFn) -> bool {
+		self.varadic == other.varadic
+			&& self.returns == other.returns
+			&& self.params.len() == other.params.len()
+			&& self.params.iter().zip(other.params.iter()).all(|((_, a), (_, b))| a == b)
+	}
+
+	/// Whether heap string literals can allocate through this function, i.e. it has C's `malloc` signature
+	fn allocates_bytes(&self) -> bool {
+		let byte = |ty: &Type| matches!(ty, Type::Primitive(Primitive::U8) | Type::Primitive(Primitive::I8) | Type::Primitive(Primitive::CChar));
+		!self.varadic
+			&& matches!(self.params.as_slice(), [(_, Type::Primitive(Primitive::USize))])
+			&& match &self.returns {
+				Some(Type::PtrMut(ty)) | Some(Type::PtrConst(ty)) => byte(ty),
+				_ => false,
+			}
+	}
+}
+
 impl Module {
-	pub fn from_ast(name: Ident, tl_decls: Vec<ast::TopLevelDecl>) -> Result<Module, LIRError> {
-		let mut name_resolve = NameResolveMap::new();
+	/// Expressions may be nested at most `recursion_limit` deep
+	pub fn from_ast(name: Ident, tl_decls: Vec<ast::TopLevelDecl>, recursion_limit: usize, target: TargetWidths) -> Result<Module, LIRError> {
+		Module::lower(name, tl_decls, true, recursion_limit, target)
+	}
+
+	/// Lower a file of `extern` declarations only, producing a module with nothing to run that can be linked against
+	pub fn declarations_from_ast(name: Ident, tl_decls: Vec<ast::TopLevelDecl>, recursion_limit: usize, target: TargetWidths) -> Result<Module, LIRError> {
+		if tl_decls.iter().any(|decl| matches!(decl, ast::TopLevelDecl::Def(_))) {
+			Err(LIRError { ty: LIRErrorType::DefinitionInDeclarations, span: None })?;
+		}
+		Module::lower(name, tl_decls, false, recursion_limit, target)
+	}
+
+	fn lower(name: Ident, tl_decls: Vec<ast::TopLevelDecl>, needs_entry: bool, recursion_limit: usize, target: TargetWidths) -> Result<Module, LIRError> {
+		let mut name_resolve = NameResolveMap::new(recursion_limit, target);
 		let mut consts = Constants {
 			strings: vec![],
 		};
 
+		// A function that is declared `extern` and defined in this module provides the declared symbol
+		let extern_names = tl_decls.iter().filter_map(|decl| match decl {
+			ast::TopLevelDecl::FnExtern(f) => Some(f.name.clone()),
+			_ => None,
+		}).collect::<HashSet<_>>();
+
 		let mut fn_decls = vec![];
 		let mut defs = vec![];
 		for decl in tl_decls {
 			match decl {
 				ast::TopLevelDecl::FnExtern(f) => {
-					fn_decls.push(DeclFn {
+					// The symbol is always the bare C name; the namespace only affects how it's referred to
+					if !f.namespace.is_empty() {
+						let mut path = f.namespace.clone();
+						path.push(f.name.clone());
+						name_resolve.extern_paths.insert(path, Ident::UnmangledItem(f.name.clone()));
+					}
+					let decl = DeclFn {
 						id: Ident::UnmangledItem(f.name),
 						params: f.params.into_iter().map(|(s, t)| Type::from_ast(t, &mut name_resolve).map(|t| (match s { Some(s) => s.to_owned(), None => "".to_owned() }, t))).collect::<Result<Vec<_>, _>>()?,
 						varadic: f.varadic,
-						returns: f.returns.map(|t| Type::from_ast(t, &mut name_resolve)).transpose()?,
-					})
+						returns: Type::return_from_ast(f.returns, &mut name_resolve)?,
+						external: true,
+					};
+					match fn_decls.iter().find(|other: &&DeclFn| other.id == decl.id) {
+						Some(other) if !other.same_signature(&decl) => Err(LIRError { ty: LIRErrorType::ConflictingDeclaration, span: None })?,
+						Some(_) => {}, // Harmless redeclaration
+						None => fn_decls.push(decl),
+					}
 				},
 				ast::TopLevelDecl::Decl(_) => {
 					
 				},
-				ast::TopLevelDecl::Def(def) => {
-					match &def {
+				ast::TopLevelDecl::Def(mut def) => {
+					match &mut def {
 						ast::TopLevelDef::Def(ast::Def::Fn(f)) => {
-							fn_decls.push(DeclFn {
-								id: Ident::UnmangledItem(f.name.clone()),
-								params: f.params.iter().cloned().map(|(s, t)| Type::from_ast(t, &mut name_resolve).map(|t| (s, t))).collect::<Result<Vec<_>, _>>()?,
+							if extern_names.contains(&f.name) {
+								f.export = true;
+							}
+							let decl = DeclFn {
+								id: fn_def_ident(f),
+								params: f.params.iter().cloned().map(|(s, t, _)| Type::from_ast(t, &mut name_resolve).map(|t| (s, t))).collect::<Result<Vec<_>, _>>()?,
 								varadic: false,
-								returns: f.returns.clone().map(|t| Type::from_ast(t, &mut name_resolve)).transpose()?,
-							})
+								returns: Type::return_from_ast(f.returns.clone(), &mut name_resolve)?,
+								external: false,
+							};
+							// The definition takes the place of an earlier matching `extern` declaration
+							match fn_decls.iter_mut().find(|other: &&mut DeclFn| other.id == decl.id) {
+								Some(other) if other.external && other.same_signature(&decl) => *other = decl,
+								Some(_) => Err(LIRError { ty: LIRErrorType::ConflictingDeclaration, span: None })?,
+								None => fn_decls.push(decl),
+							}
 						}
 						_ => {}
 					}
@@ -203,13 +391,14 @@ impl Module {
 		name_resolve.local_fns = fn_decls.iter().map(|decl| Ok((decl.id.clone(), decl.clone())))
 			.chain(
 				defs.iter().filter_map(|def| match def {
-					ast::TopLevelDef::Def(ast::Def::Fn(f)) => match f.params.iter().map(|(s, t)| match Type::from_ast(t.clone(), &mut name_resolve) { Ok(t) => Ok((s.clone(), t)), Err(e) => Err(e) }).collect() {
-						Ok(params) => match f.returns.clone().map(|t| Type::from_ast(t, &mut name_resolve)).transpose() {
-							Ok(returns) => Some(Ok((Ident::UnmangledItem(f.name.clone()), DeclFn {
-								id: Ident::UnmangledItem(f.name.clone()),
+					ast::TopLevelDef::Def(ast::Def::Fn(f)) => match f.params.iter().map(|(s, t, _)| match Type::from_ast(t.clone(), &mut name_resolve) { Ok(t) => Ok((s.clone(), t)), Err(e) => Err(e) }).collect() {
+						Ok(params) => match Type::return_from_ast(f.returns.clone(), &mut name_resolve) {
+							Ok(returns) => Some(Ok((fn_def_ident(f), DeclFn {
+								id: fn_def_ident(f),
 								params,
 								varadic: false,
 								returns,
+								external: false,
 							}))),
 							Err(e) => Some(Err(e)),
 						},
@@ -225,37 +414,76 @@ impl Module {
 		for def in defs {
 			match def {
 				ast::TopLevelDef::Entry(e) => {
-					assert!(entry.is_none(), "Multiple entry points declared!"); //TODO: Error type
+					if entry.is_some() {
+						Err(LIRError { ty: LIRErrorType::MultipleEntryPoints, span: None })?;
+					}
+					let returns = Type::return_from_ast(e.returns, &mut name_resolve)?;
 					entry = Some(DefEntry {
-						returns: e.returns.map(|t| Type::from_ast(t, &mut name_resolve)).transpose()?,
-						body: FnBody::from_ast(e.body, &mut name_resolve, &mut consts)?,
+						body: FnBody::from_ast(e.body, returns.clone(), &mut name_resolve, &mut consts)?,
+						returns,
 					})
 				},
 				ast::TopLevelDef::Def(ast::Def::Fn(f)) => {
+					let id = fn_def_ident(&f);
 					let mut scope = StackScope::default();
-					for (param, ty) in f.params {
+					let mutable_params = f.params.iter().map(|(_, _, mutable)| *mutable).collect();
+					for (param, ty, mutable) in f.params {
 						scope.vars.insert(param.clone(), Decl {
 							name: Ident::Local(param),
-							mutable: false,
+							mutable,
 							ty: Type::from_ast(ty, &mut name_resolve)?,
 						});
 					}
 					name_resolve.scope_stack.push(scope);
 					fn_defs.push(DefFn {
-						id: Ident::UnmangledItem(f.name), //TODO
-						body: FnBody::from_ast(f.body, &mut name_resolve, &mut consts)?
+						id,
+						body: FnBody::from_ast(f.body, name_resolve.local_fns[&id].returns.clone(), &mut name_resolve, &mut consts)?,
+						mutable_params,
 					});
 					name_resolve.scope_stack.pop();
 				}
 			}
 		}
 
+		// Without an entry block, a function called `main` can serve as the entry point instead
+		if let Some(main_fn) = name_resolve.resolve_fn_default(vec!["main".to_owned()]).filter(|decl| !decl.external).cloned() {
+			if entry.is_some() {
+				Err(LIRError { ty: LIRErrorType::MultipleEntryPoints, span: None })?;
+			}
+			match main_fn.returns {
+				None | Some(Type::Primitive(Primitive::CInt)) if main_fn.params.is_empty() => {},
+				_ => Err(LIRError { ty: LIRErrorType::InvalidMainSignature, span: None })?,
+			}
+			// An exported main already has the right symbol; otherwise it's called from a generated one
+			if main_fn.id != Ident::UnmangledItem("main".to_owned()) {
+				entry = Some(DefEntry {
+					returns: main_fn.returns.clone(),
+					body: FnBody {
+						decls: vec![],
+						block: Block {
+							statements: vec![],
+							tail: Some(Expression {
+								ty: main_fn.returns.clone(),
+								value: ExpressionValue::CallConcrete(main_fn.id.clone(), vec![]),
+							}),
+						},
+					},
+				});
+			}
+		}
+
+		// A module with nothing to run would compile to an object with no code in it at all, which is never what was meant
+		if needs_entry && entry.is_none() && fn_defs.is_empty() {
+			Err(LIRError { ty: LIRErrorType::NoEntryPoint, span: None })?;
+		}
+
 		Ok(Module {
 			name,
 			fn_decls,
 			fn_defs,
 			consts,
 			entry,
+			warnings: name_resolve.warnings,
 		})
 	}
 
@@ -271,11 +499,24 @@ impl Module {
 }
 
 impl FnBody {
-	fn from_ast(block: ast::Block, name_resolve: &mut NameResolveMap, consts: &mut Constants) -> Result<FnBody, LIRError> {
+	fn from_ast(block: ast::Block, returns: Option<Type>, name_resolve: &mut NameResolveMap, consts: &mut Constants) -> Result<FnBody, LIRError> {
 		let mut decls = vec![];
+		name_resolve.init = InitState::default();
+		name_resolve.returns = returns;
+
+		let mut block = Block::from_ast(block, name_resolve, &mut decls, &mut Vec::new(), consts)?;
+		// The tail is the return value, so it has to have the declared return type as well
+		let diverges = block.diverges();
+		block.tail = match (block.tail, &name_resolve.returns) {
+			(Some(tail), Some(returns)) => Some(tail.coerce(returns).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?),
+			(Some(tail), None) if tail.is_void_compatible() => Some(tail),
+			(None, None) => None,
+			(None, Some(_)) if diverges => None,
+			_ => Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?,
+		};
 
 		Ok(FnBody {
-			block: Block::from_ast(block, name_resolve, &mut decls, &mut Vec::new(), consts)?,
+			block,
 			decls,
 		})
 	}
@@ -287,41 +528,80 @@ impl Block {
 		name_resolve.scope_stack.push(StackScope::default());
 
 		for statement in block.statements {
+			// The backend stops at the first statement that leaves the block, so anything after it would be lost.
+			// A tail is still allowed, since it's never evaluated either way.
+			if statements.last().map(Statement::diverges).unwrap_or(false) {
+				Err(LIRError { ty: LIRErrorType::UnreachableStatement, span: None })?;
+			}
 			match statement {
 				ast::Statement::Expression(e) => {
 					statements.push(Statement::Eval(Expression::from_ast(e, name_resolve, decls, loops, consts)?))
 				},
-				ast::Statement::Break(e) => {
+				ast::Statement::Break(label, e, span) => {
 					let expr = e.map(|e| Expression::from_ast(e, name_resolve, decls, loops, consts)).transpose()?;
-					if let Some(loop_bk) = loops.last_mut() {
-						if let Some(ty) = loop_bk.ty.as_ref() {
-							if expr.as_ref().and_then(|e| e.ty.as_ref()) != ty.as_ref() {
-								Err(LIRError { ty: LIRErrorType::MismatchedTypes })?;
-							}
-						} else {
-							loop_bk.ty = Some(expr.as_ref().and_then(|e| e.ty.clone()));
+					let loop_bk = match &label {
+						Some(label) => loops.iter_mut().rev().find(|l| l.name == *label).ok_or_else(|| LIRError { ty: LIRErrorType::UndefinedLabel(label.clone()), span: Some(span) })?,
+						None => loops.last_mut().ok_or(LIRError { ty: LIRErrorType::BreakOutsideLoop, span: Some(span) })?,
+					};
+					let break_ty = expr.as_ref().and_then(|e| e.ty.clone());
+					// The first break decides the type of the loop; every later one must agree with it
+					if let Some(loop_ty) = &loop_bk.ty {
+						if *loop_ty != break_ty {
+							Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: Some(span) })?;
 						}
-						statements.push(Statement::Break(expr));
 					} else {
-						Err(LIRError { ty: LIRErrorType::BreakOutsideLoop })?;
+						loop_bk.ty = Some(break_ty);
 					}
+					loop_bk.exits.get_or_insert_with(InitState::default).merge(name_resolve.init.clone());
+					statements.push(Statement::Break(label, expr));
+				}
+				ast::Statement::Continue(label) => {
+					let loop_bk = match &label {
+						Some(label) => loops.iter_mut().rev().find(|l| l.name == *label).ok_or_else(|| LIRError { ty: LIRErrorType::UndefinedLabel(label.clone()), span: None })?,
+						None => loops.last_mut().ok_or(LIRError { ty: LIRErrorType::ContinueOutsideLoop, span: None })?,
+					};
+					loop_bk.repeats.merge(name_resolve.init.clone());
+					statements.push(Statement::Continue(label));
 				}
 				ast::Statement::Return(e) => {
-					statements.push(Statement::Return(e.map(|e| Expression::from_ast(e, name_resolve, decls, loops, consts)).transpose()?))
+					let span = e.as_ref().and_then(ast::Expression::span);
+					let expr = e.map(|e| Expression::from_ast(e, name_resolve, decls, loops, consts)).transpose()?;
+					// The backend relies on returned values already having the function's return type
+					let expr = match (expr, &name_resolve.returns) {
+						(Some(expr), Some(returns)) => Some(expr.require_value(span)?.coerce(returns).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?),
+						(Some(expr), None) if expr.is_void_compatible() => Some(expr),
+						(None, None) => None,
+						_ => Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?,
+					};
+					statements.push(Statement::Return(expr))
 				}
-				ast::Statement::Decl { name, mutable, expected_type, value } => {
-					let mut expr = Expression::from_ast(value, name_resolve, decls, loops, consts)?;
-					if let Some(expected) = expected_type {
-						expr = expr.coerce(&Type::from_ast(expected, name_resolve)?).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes })?;
-					}
+				ast::Statement::Decl { name, mutable, expected_type, value, span } => {
+					let expected_type = expected_type.map(|t| Type::from_ast(t, name_resolve)).transpose()?;
+					let expr = match (value, &expected_type) {
+						(Some(value), Some(expected)) => Some(Expression::value_from_ast(value, name_resolve, decls, loops, consts)?.coerce(expected).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?),
+						(Some(value), None) => Some(Expression::value_from_ast(value, name_resolve, decls, loops, consts)?),
+						(None, _) => None,
+					};
+					let ty = match &expr {
+						Some(expr) => expr.ty.clone().expect("Values are always typed"),
+						None => expected_type.expect("Grammar requires a type on uninitialized declarations"),
+					};
+					// Every binding gets its own local, even if it shadows another with the same name. `.` can't appear in
+					// source identifiers, so this can't collide with anything the user wrote.
 					let decl = Decl {
-						name: Ident::Local(name.clone()),
+						name: Ident::Local(format!("{}.{}", name, decls.len())),
 						mutable,
-						ty: expr.ty.clone().ok_or(LIRError { ty: LIRErrorType::VoidValue })?,
+						ty,
 					};
 					decls.push(decl.clone());
-					name_resolve.scope_stack.last_mut().expect("One was pushed on earlier").vars.insert(name.clone(), decl.clone());
-					statements.push(Statement::Decl(name, expr));
+					// Nested blocks shadowing is expected, but rebinding a name within one block is more likely a mistake
+					if name_resolve.scope_stack.last_mut().expect("One was pushed on earlier").vars.insert(name.clone(), decl.clone()).is_some() {
+						name_resolve.warnings.push(Diagnostic::warning(format!("`{}` is already bound in this block; this `let` shadows it", name), Some(span)));
+					}
+					match expr {
+						Some(expr) => statements.push(Statement::Decl(decl.name.local_mangle(), expr)),
+						None => { name_resolve.init.uninit.insert(decl.name); },
+					}
 				}
 			}
 		}
@@ -337,31 +617,89 @@ impl Block {
 	}
 }
 
+impl Block {
+	/// Whether control flow unconditionally leaves this block before reaching its end, through a `return`, a `break`,
+	/// or a diverging expression
+	pub fn diverges(&self) -> bool {
+		self.statements.iter().any(Statement::diverges) || self.tail.as_ref().map(Expression::diverges).unwrap_or(false)
+	}
+}
+
+impl Statement {
+	/// Whether control flow never continues to the statement after this one
+	pub fn diverges(&self) -> bool {
+		match self {
+			Statement::Return(_) | Statement::Break(..) | Statement::Continue(_) => true,
+			Statement::Eval(e) | Statement::Decl(_, e) => e.diverges(),
+		}
+	}
+}
+
 impl LExpression {
-	fn from_ast(expression: ast::Expression, name_resolve: &mut NameResolveMap, _decls: &mut Vec<Decl>, _consts: &mut Constants) -> Result<LExpression, LIRError> {
+	fn from_ast(expression: ast::Expression, name_resolve: &mut NameResolveMap, decls: &mut Vec<Decl>, loops: &mut Vec<LoopBreak>, consts: &mut Constants) -> Result<LExpression, LIRError> {
 		Ok(match expression {
-			ast::Expression::LVar(i) => {
-				let Decl { ty, name, mutable, ..} = name_resolve.resolve_var_default(i).ok_or(LIRError { ty: LIRErrorType::UnresolvedIdent })?;
+			ast::Expression::LVar(i, span) => {
+				let decl = match name_resolve.resolve_var_default(i.clone()) {
+					Some(decl) => decl,
+					None if name_resolve.resolve_fn_default(i.clone()).is_some() || name_resolve.resolve_typename_default(i.clone()).is_some() => {
+						Err(LIRError { ty: LIRErrorType::AssignToNonVariable(i.join("::")), span: Some(span) })?
+					},
+					None => Err(name_resolve.unresolved_var(&i, span))?,
+				};
+				let Decl { ty, name, mutable, ..} = decl;
 				LExpression {
 					ty,
 					mutable,
 					value: LExpressionValue::Var(name),
 				}
 			},
-			_ => Err(LIRError { ty: LIRErrorType::InvalidLValueExpr })?
+			ast::Expression::Deref(e) => {
+				let pointer = Expression::value_from_ast(*e, name_resolve, decls, loops, consts)?;
+				// Writing through a pointer is only allowed if the pointer permits it
+				let (ty, mutable) = match &pointer.ty {
+					Some(Type::PtrConst(t)) => ((**t).clone(), false),
+					Some(Type::PtrMut(t)) => ((**t).clone(), true),
+					_ => Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?,
+				};
+				LExpression {
+					ty,
+					mutable,
+					value: LExpressionValue::Deref(Box::new(pointer)),
+				}
+			},
+			_ => Err(LIRError { ty: LIRErrorType::InvalidLValueExpr, span: None })?
 		})
 	}
 }
 
 impl Expression {
 	fn from_ast(expression: ast::Expression, name_resolve: &mut NameResolveMap, decls: &mut Vec<Decl>, loops: &mut Vec<LoopBreak>, consts: &mut Constants) -> Result<Expression, LIRError> {
+		// Lowering (and later codegen) recurses once per level of nesting, so pathologically deep input has to be cut
+		// off before it overflows the stack
+		if name_resolve.depth >= name_resolve.recursion_limit {
+			Err(LIRError { ty: LIRErrorType::RecursionLimitExceeded, span: None })?;
+		}
+		name_resolve.depth += 1;
+		let result = Expression::from_ast_nested(expression, name_resolve, decls, loops, consts);
+		name_resolve.depth -= 1;
+		result
+	}
+
+	fn from_ast_nested(expression: ast::Expression, name_resolve: &mut NameResolveMap, decls: &mut Vec<Decl>, loops: &mut Vec<LoopBreak>, consts: &mut Constants) -> Result<Expression, LIRError> {
 		Ok(match expression {
 			ast::Expression::Assign(lhs, op, rhs) => {
-				let lvalue = LExpression::from_ast(*lhs, name_resolve, decls, consts)?;
-				if !lvalue.mutable {
-					Err(LIRError { ty: LIRErrorType::ImmutAssign })?;
+				let lvalue = LExpression::from_ast(*lhs, name_resolve, decls, loops, consts)?;
+				let rvalue = Expression::value_from_ast(*rhs, name_resolve, decls, loops, consts)?.coerce(&lvalue.ty).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?;
+				// The first plain assignment to an uninitialized variable initializes it, even if it isn't mutable.
+				// That only holds if no path reaching it has assigned the variable already.
+				let initializes = match &lvalue.value {
+					LExpressionValue::Var(name) if op.is_none() && name_resolve.init.uninit.remove(name) => name_resolve.init.assigned.insert(name.clone()),
+					LExpressionValue::Var(name) if name_resolve.init.uninit.contains(name) => Err(LIRError { ty: LIRErrorType::UseBeforeInit, span: None })?,
+					_ => false,
+				};
+				if !lvalue.mutable && !initializes {
+					Err(LIRError { ty: LIRErrorType::ImmutAssign, span: None })?;
 				}
-				let rvalue = Expression::from_ast(*rhs, name_resolve, decls, loops, consts)?.coerce(&lvalue.ty).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes })?;
 
 				Expression {
 					ty: Some(lvalue.ty.clone()),
@@ -369,20 +707,99 @@ impl Expression {
 				}
 			},
 			ast::Expression::Op(op, lhs, rhs) => {
+				let lhs = Expression::value_from_ast(*lhs, name_resolve, decls, loops, consts)?;
+				let rhs = Expression::value_from_ast(*rhs, name_resolve, decls, loops, consts)?;
+				// Pointer arithmetic: offsetting a pointer by an integer number of elements, or the distance between two
+				if let (Op::Add, Some(l), Some(Type::Primitive(r))) | (Op::Sub, Some(l), Some(Type::Primitive(r))) = (op, &lhs.ty, &rhs.ty) {
+					if l.is_thin_pointer() && r.is_numeric() && !r.is_float() {
+						return Ok(Expression {
+							ty: lhs.ty.clone(),
+							value: ExpressionValue::Op(op, Box::new(lhs), Box::new(rhs)),
+						});
+					}
+				}
+				if let (Op::Sub, Some(l), Some(r)) = (op, &lhs.ty, &rhs.ty) {
+					if l.is_thin_pointer() && l == r {
+						return Ok(Expression {
+							ty: Some(Type::Primitive(Primitive::ISize)),
+							value: ExpressionValue::Op(op, Box::new(lhs), Box::new(rhs)),
+						});
+					}
+				}
+				for operand in &[&lhs, &rhs] {
+					let valid = match (op, &operand.ty) {
+						(Op::Eq, Some(Type::Primitive(_))) => true,
+						(_, Some(Type::Primitive(p))) => p.is_numeric(),
+						_ => false,
+					};
+					if !valid {
+						Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?;
+					}
+				}
+				// A literal takes the type of the other operand, as long as its value fits. Between two literals, the
+				// one with an unsuffixed literal's type gives way.
+				let gives_way = |e: &Expression, other: &Expression| e.is_literal() && (!other.is_literal() || matches!(e.ty, Some(Type::Primitive(Primitive::I32)) | Some(Type::Primitive(Primitive::F64))));
+				let (lhs, rhs) = match (lhs.ty.clone(), rhs.ty.clone()) {
+					(Some(l), Some(r)) if l != r && gives_way(&rhs, &lhs) => (lhs, rhs.coerce_literal(&l, &name_resolve.target).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?),
+					(Some(l), Some(r)) if l != r && lhs.is_literal() => (lhs.coerce_literal(&r, &name_resolve.target).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?, rhs),
+					_ => (lhs, rhs),
+				};
+				// Otherwise nothing converts implicitly, between integer widths, signedness or integers and floats. Comparing
+				// signed with unsigned would have to pick one interpretation of the bits, so both sides agree up front.
+				if lhs.ty != rhs.ty {
+					Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?;
+				}
+				Expression {
+					ty: match op {
+						Op::Eq | Op::Gt | Op::Ge | Op::Lt | Op::Le => Some(Type::Primitive(Primitive::Bool)),
+						_ => lhs.ty.clone(),
+					},
+					value: ExpressionValue::Op(op, Box::new(lhs), Box::new(rhs)),
+				}
+			},
+			ast::Expression::Not(e) => {
+				let e = Expression::value_from_ast(*e, name_resolve, decls, loops, consts)?;
+				if e.ty != Some(Type::Primitive(Primitive::Bool)) {
+					Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?;
+				}
+				Expression {
+					ty: e.ty.clone(),
+					value: ExpressionValue::Not(Box::new(e)),
+				}
+			},
+			ast::Expression::BitNot(e) => {
+				let e = Expression::value_from_ast(*e, name_resolve, decls, loops, consts)?;
+				match &e.ty {
+					Some(Type::Primitive(p)) if p.is_numeric() && !p.is_float() => {},
+					_ => Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?,
+				}
+				Expression {
+					ty: e.ty.clone(),
+					value: ExpressionValue::Not(Box::new(e)),
+				}
+			},
+			ast::Expression::Cast(e, ty) => {
+				let e = Expression::value_from_ast(*e, name_resolve, decls, loops, consts)?;
+				let ty = Type::from_ast(ty, name_resolve)?;
+				let valid = match (&e.ty, &ty) {
+					(Some(Type::Primitive(from)), Type::Primitive(to)) => !from.is_float() && !to.is_float(),
+					(Some(from), to) => from.is_thin_pointer() && to.is_thin_pointer(),
+					(None, _) => false,
+				};
+				if !valid {
+					Err(LIRError { ty: LIRErrorType::InvalidCast, span: None })?;
+				}
 				Expression {
-					ty: Some(Type::Primitive(match op {
-						Op::Eq | Op::Gt | Op::Ge | Op::Lt | Op::Le => Primitive::Bool,
-						_ => Primitive::I32
-					})), //TODO !!
-					value: ExpressionValue::Op(op, Box::new(Expression::from_ast(*lhs, name_resolve, decls, loops, consts)?), Box::new(Expression::from_ast(*rhs, name_resolve, decls, loops, consts)?)),
+					ty: Some(ty),
+					value: ExpressionValue::Cast(Box::new(e)),
 				}
 			},
-			ast::Expression::Call(f, mut a) => {
+			ast::Expression::Call(f, mut a, _) => {
 				match *f {
-					ast::Expression::LVar(n) => {
-						let decl = name_resolve.resolve_fn_default(n).ok_or(LIRError { ty: LIRErrorType::UnresolvedIdent })?.clone();
+					ast::Expression::LVar(n, span) => {
+						let decl = name_resolve.resolve_fn_default(n.clone()).ok_or_else(|| name_resolve.unresolved_fn(&n, span))?.clone();
 						if if decl.varadic { a.len() < decl.params.len() } else { a.len() != decl.params.len() } {
-							Err(LIRError { ty: LIRErrorType::ArgCountMismatch })?;
+							Err(LIRError { ty: LIRErrorType::ArgCountMismatch, span: None })?;
 						}
 
 						let varargs = if a.len() == decl.params.len() {
@@ -393,65 +810,242 @@ impl Expression {
 
 						let args = a.into_iter()
 							.zip(decl.params.iter())
-							.map(|(e, (_, ty))| Expression::from_ast(e, name_resolve, decls, loops, consts)?
-								.coerce(ty).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes })
+							.map(|(e, (_, ty))| Expression::value_from_ast(e, name_resolve, decls, loops, consts)?
+								.coerce(ty).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })
 							)
 							.collect::<Vec<_>>()
 							.into_iter()
 							.chain(varargs.into_iter()
-								.map(|e| Expression::from_ast(e, name_resolve, decls, loops, consts))
+								.map(|e| Expression::value_from_ast(e, name_resolve, decls, loops, consts))
 							)
 							.collect::<Result<Vec<_>, _>>()?;
 
 						Expression {
-							ty: decl.returns.clone(),
+							// A Lok function that returns nothing returns `()`, like its body's tail. Only a C function
+							// declared without a return type really has no value.
+							ty: match &decl.returns {
+								None if !decl.external => Some(Type::Tuple(vec![])),
+								returns => returns.clone(),
+							},
 							value: ExpressionValue::CallConcrete(decl.id.clone(), args),
 						}
 					},
 					_ => todo!(),
 				}
 			},
-			ast::Expression::Int(i) => {
+			ast::Expression::Field(e, field) => {
+				let e = Expression::value_from_ast(*e, name_resolve, decls, loops, consts)?;
+				match (&e.ty, str::parse::<u32>(&field)) {
+					(Some(Type::Tuple(types)), Ok(i)) if (i as usize) < types.len() => Expression {
+						ty: Some(types[i as usize].clone()),
+						value: ExpressionValue::TupleField(Box::new(e), i),
+					},
+					(Some(Type::PtrDynConst(_)), Ok(0)) | (Some(Type::PtrDynMut(_)), Ok(0)) | (Some(Type::Slice(_)), Ok(0)) => Expression {
+						ty: Some(Type::Primitive(Primitive::USize)),
+						value: ExpressionValue::TupleField(Box::new(e), 0),
+					},
+					(Some(Type::PtrDynConst(t)), Ok(1)) | (Some(Type::Slice(t)), Ok(1)) => Expression {
+						ty: Some(Type::PtrConst(t.clone())),
+						value: ExpressionValue::TupleField(Box::new(e), 1),
+					},
+					(Some(Type::PtrDynMut(t)), Ok(1)) => Expression {
+						ty: Some(Type::PtrMut(t.clone())),
+						value: ExpressionValue::TupleField(Box::new(e), 1),
+					},
+					_ => Err(LIRError { ty: LIRErrorType::NoSuchField, span: None })?,
+				}
+			},
+			ast::Expression::MakeDyn(pointer, metadata) => {
+				let pointer = Expression::value_from_ast(*pointer, name_resolve, decls, loops, consts)?;
+				let metadata = Expression::value_from_ast(*metadata, name_resolve, decls, loops, consts)?;
+				match &metadata.ty {
+					Some(Type::Primitive(p)) if p.is_numeric() && !p.is_float() => {},
+					_ => Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?,
+				}
+				let ty = match &pointer.ty {
+					Some(Type::PtrConst(t)) => Type::PtrDynConst(t.clone()),
+					Some(Type::PtrMut(t)) => Type::PtrDynMut(t.clone()),
+					_ => Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?,
+				};
 				Expression {
-					ty: Some(integer_type_for_value(i)),
-					value: ExpressionValue::ConstInt(i)
+					ty: Some(ty),
+					value: ExpressionValue::MakeDyn(Box::new(pointer), Box::new(metadata)),
+				}
+			},
+			// ASCII fits in a C `char` whatever its signedness; anything else needs the whole code point
+			ast::Expression::Char(c) => Expression {
+				ty: Some(Type::Primitive(if c < 0x80 { Primitive::CChar } else { Primitive::U32 })),
+				value: ExpressionValue::ConstInt(c as u128),
+			},
+			ast::Expression::Float(f) => Expression {
+				ty: Some(Type::Primitive(Primitive::F64)),
+				value: ExpressionValue::ConstFloat(f),
+			},
+			ast::Expression::SizeOf(ty) => Expression {
+				ty: Some(Type::Primitive(Primitive::USize)),
+				value: ExpressionValue::SizeOf(Type::from_ast(ty, name_resolve)?),
+			},
+			ast::Expression::AlignOf(ty) => Expression {
+				ty: Some(Type::Primitive(Primitive::USize)),
+				value: ExpressionValue::AlignOf(Type::from_ast(ty, name_resolve)?),
+			},
+			ast::Expression::Int(literal) => {
+				let ty = match &literal.suffix {
+					Some(p) if literal.fits(p, &name_resolve.target) => Some(Type::Primitive(p.clone())),
+					Some(_) => None,
+					None => integer_type_for_literal(&literal, &name_resolve.target),
+				};
+				Expression {
+					ty: Some(ty.ok_or_else(|| LIRError { ty: LIRErrorType::IntegerOverflow(literal.text.clone()), span: None })?),
+					value: ExpressionValue::ConstInt(literal.bits()),
 				}
 			},
 			ast::Expression::Block(b) => {
 				let ir = Block::from_ast(*b, name_resolve, decls, loops, consts)?;
 				Expression {
-					ty: ir.tail.as_ref().and_then(|e| e.ty.clone()),
+					ty: if ir.diverges() { Some(Type::Never) } else { ir.tail.as_ref().and_then(|e| e.ty.clone()) },
 					value: ExpressionValue::Block(Box::new(ir)),
 				}
 			},
 			ast::Expression::If(i) => {
 				let ir = If::from_ast(i, name_resolve, decls, loops, consts)?;
 				Expression {
-					ty: ir.1.tail.as_ref().and_then(|e| e.ty.clone()),
+					ty: ir.ty(),
 					value: ExpressionValue::If(ir)
 				}
 			},
-			ast::Expression::Loop(b) => {
+			ast::Expression::Match(scrutinee, arms) => {
+				let scrutinee = Expression::value_from_ast(*scrutinee, name_resolve, decls, loops, consts)?;
+				let scrutinee_ty = match &scrutinee.ty {
+					Some(Type::Primitive(p)) if p.is_numeric() && !p.is_float() => p.clone(),
+					_ => Err(LIRError { ty: LIRErrorType::InvalidOperandType, span: None })?,
+				};
+				// Like `if`, a variable is only initialized after the match if every arm that completes initializes it
+				let init_before = name_resolve.init.clone();
+				let mut init_after = InitState::default();
+				let mut cases: Vec<(u128, Block)> = vec![];
+				let mut default: Option<Box<Block>> = None;
+				for (pattern, arm) in arms {
+					// Every arm is checked, even one that can never be reached
+					name_resolve.init = init_before.clone();
+					let arm = Expression::from_ast(arm, name_resolve, decls, loops, consts)?;
+					let block = Block { statements: vec![], tail: Some(arm) };
+					let case = match pattern {
+						Some(literal) if literal.suffix.as_ref().map(|suffix| *suffix != scrutinee_ty).unwrap_or(false) => Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?,
+						Some(literal) if !literal.fits(&scrutinee_ty, &name_resolve.target) => Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?,
+						Some(literal) => Some(literal.bits()),
+						None => None,
+					};
+					if default.is_some() || case.map(|value| cases.iter().any(|(c, _)| *c == value)).unwrap_or(false) {
+						name_resolve.warnings.push(Diagnostic::warning("unreachable match arm; an earlier arm already matches its value", None));
+						continue;
+					}
+					if !block.diverges() {
+						init_after.merge(std::mem::take(&mut name_resolve.init));
+					}
+					match case {
+						Some(value) => cases.push((value, block)),
+						None => default = Some(Box::new(block)),
+					}
+				}
+				let mut default = default.ok_or(LIRError { ty: LIRErrorType::NonExhaustiveMatch, span: None })?;
+				let ty = unify_branches(cases.iter_mut().map(|(_, b)| b).chain(std::iter::once(&mut *default)).collect(), &name_resolve.target)?;
+				name_resolve.init = if ty.is_some() { init_after } else { init_before };
+				Expression {
+					ty: ty.unwrap_or(Some(Type::Never)),
+					value: ExpressionValue::Match(Box::new(scrutinee), cases, default),
+				}
+			},
+			ast::Expression::Loop(label, b) => {
+				let init_before = name_resolve.init.clone();
 				loops.push(LoopBreak {
-				    name: "".to_owned(),
+				    name: label.clone().unwrap_or_default(),
 				    ty: None,
+				    exits: None,
+				    repeats: InitState::default(),
 				});
 				let block = Block::from_ast(*b, name_resolve, decls, loops, consts)?;
-				let brk = loops.pop().unwrap();
+				let mut brk = loops.pop().unwrap();
+				if !block.diverges() {
+					brk.repeats.merge(std::mem::take(&mut name_resolve.init));
+				}
+				// The body runs again after a continue or reaching its end, so whatever it may have assigned is
+				// possibly assigned again on the next pass
+				for name in brk.repeats.assigned.difference(&init_before.assigned) {
+					if init_before.uninit.contains(name) && !decls.iter().any(|d| d.name == *name && d.mutable) {
+						Err(LIRError { ty: LIRErrorType::ImmutAssign, span: None })?;
+					}
+				}
+				// After the loop, a variable is only initialized if every break initialized it
+				name_resolve.init = brk.exits.unwrap_or(init_before);
 				Expression {
 					ty: brk.ty.unwrap_or(Some(Type::Never)),
-					value: ExpressionValue::Loop(Box::new(block)),
+					value: ExpressionValue::Loop(label, Box::new(block)),
 				}
 			},
+			ast::Expression::Unit => Expression {
+				ty: Some(Type::Tuple(vec![])),
+				value: ExpressionValue::Unit,
+			},
 			ast::Expression::CStringRef(s) => {
-				consts.strings.push((s, true));
+				consts.strings.push((s, true, StringStorage::Static));
 				Expression {
 					ty: Some(Type::PtrConst(Box::new(Type::Primitive(Primitive::CChar)))),
 					value: ExpressionValue::ConstStr(consts.strings.len() - 1),
 				}
 			},
-			ast::Expression::LVar(_) => {
-				let lexpr = LExpression::from_ast(expression, name_resolve, decls, consts)?;
+			ast::Expression::ByteStringRef(s) => {
+				let len = s.len() as u64;
+				consts.strings.push((s, false, StringStorage::Static));
+				Expression {
+					ty: Some(Type::PtrConst(Box::new(Type::Primitive(Primitive::U8)))),
+					value: ExpressionValue::ConstBytes(consts.strings.len() - 1, len),
+				}
+			},
+			ast::Expression::LokStringHeap(s) => {
+				check_malloc(name_resolve)?;
+				let len = s.len() as u128;
+				consts.strings.push((s, false, StringStorage::Heap));
+				// Lok strings carry their length instead of a terminator
+				let byte = Type::Primitive(Primitive::U8);
+				Expression {
+					ty: Some(Type::PtrDynMut(Box::new(byte.clone()))),
+					value: ExpressionValue::MakeDyn(
+						Box::new(Expression {
+							ty: Some(Type::PtrMut(Box::new(byte))),
+							value: ExpressionValue::ConstStr(consts.strings.len() - 1),
+						}),
+						Box::new(Expression {
+							ty: Some(Type::Primitive(Primitive::USize)),
+							value: ExpressionValue::ConstInt(len),
+						}),
+					),
+				}
+			},
+			ast::Expression::CStringHeap(s) => {
+				check_malloc(name_resolve)?;
+				consts.strings.push((s, true, StringStorage::Heap));
+				// The allocation belongs to whoever evaluated the literal, so it may be written to
+				Expression {
+					ty: Some(Type::PtrMut(Box::new(Type::Primitive(Primitive::CChar)))),
+					value: ExpressionValue::ConstStr(consts.strings.len() - 1),
+				}
+			},
+			// A function named where a value is expected evaluates to a pointer to it, unless a variable shadows it
+			ast::Expression::LVar(name, _) if name_resolve.resolve_var_default(name.clone()).is_none() && name_resolve.resolve_fn_default(name.clone()).is_some() => {
+				let decl = name_resolve.resolve_fn_default(name).expect("Checked in guard");
+				Expression {
+					ty: Some(Type::Fn(decl.params.iter().map(|(_, ty)| ty.clone()).collect(), decl.returns.clone().map(Box::new), decl.varadic)),
+					value: ExpressionValue::FnRef(decl.id.clone()),
+				}
+			},
+			ast::Expression::LVar(..) | ast::Expression::Deref(_) => {
+				let lexpr = LExpression::from_ast(expression, name_resolve, decls, loops, consts)?;
+				if let LExpressionValue::Var(name) = &lexpr.value {
+					if name_resolve.init.uninit.contains(name) {
+						Err(LIRError { ty: LIRErrorType::UseBeforeInit, span: None })?;
+					}
+				}
 				Expression {
 					ty: Some(lexpr.ty.clone()),
 					value: ExpressionValue::LExpr(lexpr),
@@ -460,8 +1054,34 @@ impl Expression {
 		})
 	}
 
+	/// Whether evaluating this expression never completes, i.e. control flow never continues past it
+	pub fn diverges(&self) -> bool {
+		self.ty == Some(Type::Never)
+	}
+
+	/// Whether this expression can be the result of a function that returns nothing
+	fn is_void_compatible(&self) -> bool {
+		self.ty.as_ref().map(|ty| *ty == Type::Never || ty.is_unit()).unwrap_or(true)
+	}
+
+	/// Rejects expressions that don't produce a value, such as calls to C functions that return nothing, where one is needed.
+	/// `span` is where the expression is, if known.
+	fn require_value(self, span: Option<(usize, usize)>) -> Result<Expression, LIRError> {
+		match self.ty {
+			Some(_) => Ok(self),
+			None => Err(LIRError { ty: LIRErrorType::VoidValue, span }),
+		}
+	}
+
+	/// Lower an expression whose value is needed
+	fn value_from_ast(expression: ast::Expression, name_resolve: &mut NameResolveMap, decls: &mut Vec<Decl>, loops: &mut Vec<LoopBreak>, consts: &mut Constants) -> Result<Expression, LIRError> {
+		let span = expression.span();
+		Expression::from_ast(expression, name_resolve, decls, loops, consts)?.require_value(span)
+	}
+
 	fn coerce(mut self, target_type: &Type) -> Option<Expression> {
-		if self.ty.as_ref().map(|t| t == target_type).unwrap_or(false) {
+		// Never coerces to anything; no value is ever produced so there's nothing to convert
+		if self.ty.as_ref().map(|t| t == target_type || *t == Type::Never).unwrap_or(false) {
 			return Some(self);
 		}
 		Some(match (self.ty, target_type) {
@@ -469,86 +1089,242 @@ impl Expression {
 				self.ty = Some(Type::Primitive(Primitive::CInt));
 				self
 			},
-			_ => todo!(),
+			// Float literals are `f64` unless they're needed as an `f32`
+			(Some(Type::Primitive(Primitive::F64)), Type::Primitive(Primitive::F32)) if matches!(self.value, ExpressionValue::ConstFloat(_)) => {
+				self.ty = Some(Type::Primitive(Primitive::F32));
+				self
+			},
+			// A byte string of the right length can be the array itself, not just a pointer to it
+			(Some(Type::PtrConst(t)), Type::Arr(a, n)) if *t == Type::Primitive(Primitive::U8) && t == *a && matches!(self.value, ExpressionValue::ConstBytes(_, len) if len == *n) => Expression {
+				ty: Some(target_type.clone()),
+				value: self.value,
+			},
+			(Some(Type::Arr(t, n)), Type::Slice(s)) if t == *s => Expression {
+				ty: Some(target_type.clone()),
+				value: ExpressionValue::ArrToSlice(Box::new(Expression { ty: Some(Type::Arr(t, n)), value: self.value })),
+			},
+			_ => return None,
 		})
 	}
+
+	fn is_literal(&self) -> bool {
+		matches!(self.value, ExpressionValue::ConstInt(_) | ExpressionValue::ConstFloat(_))
+	}
+
+	/// Give a literal the type of the expression it's used with, if its value can be represented in that type
+	fn coerce_literal(mut self, target_type: &Type, target: &TargetWidths) -> Option<Expression> {
+		let value = match (&self.value, &self.ty, target_type) {
+			(ExpressionValue::ConstInt(i), Some(Type::Primitive(from)), Type::Primitive(_)) if from.is_signed() => *i as i128,
+			(ExpressionValue::ConstInt(i), Some(Type::Primitive(_)), Type::Primitive(_)) => i128::try_from(*i).ok()?,
+			_ => return self.coerce(target_type),
+		};
+		match target_type {
+			Type::Primitive(p) if p.can_hold(value, target) => {
+				self.ty = Some(target_type.clone());
+				Some(self)
+			},
+			_ => None,
+		}
+	}
 }
 
 impl If {
 	fn from_ast(ast: ast::If, name_resolve: &mut NameResolveMap, decls: &mut Vec<Decl>, loops: &mut Vec<LoopBreak>, consts: &mut Constants) -> Result<If, LIRError> {
 		let ast::If(cond, true_branch, false_branch) = ast;
-		let condition = Expression::from_ast(*cond, name_resolve, decls, loops, consts)?.coerce(&Type::Primitive(Primitive::Bool)).ok_or(LIRError { ty: LIRErrorType::IllegalConditionExpr })?;
+		// Integers aren't implicitly truthy; `as bool` says which test is meant
+		let condition = Expression::value_from_ast(*cond, name_resolve, decls, loops, consts)?;
+		let found = condition.ty.as_ref().map(Type::to_string).unwrap_or_default();
+		let condition = condition.coerce(&Type::Primitive(Primitive::Bool)).ok_or(LIRError { ty: LIRErrorType::IllegalConditionExpr(found), span: None })?;
+		// A variable is only initialized after the if when every branch that completes initializes it
+		let init_before = name_resolve.init.clone();
 		let true_block = Block::from_ast(*true_branch, name_resolve, decls, loops, consts)?;
+		let init_after_true = std::mem::replace(&mut name_resolve.init, init_before);
 		let false_item = match false_branch {
 			Some(Left(i)) => {
 				let c = If::from_ast(*i, name_resolve, decls, loops, consts)?;
-				Some(Box::new(Block { statements: vec![], tail: Some(Expression { ty: c.1.tail.as_ref().and_then(|e| e.ty.clone()), value: ExpressionValue::If(c) }) }))
+				Some(Box::new(Block { statements: vec![], tail: Some(Expression { ty: c.ty(), value: ExpressionValue::If(c) }) }))
 			},
 			Some(Right(b)) => Some(Box::new(Block::from_ast(*b, name_resolve, decls, loops, consts)?)),
 			None => None
 		};
-		let lir = If(Box::new(condition), Box::new(true_block), false_item);
-		if lir.1.tail.as_ref().and_then(|tail| tail.ty.as_ref()) == lir.2.as_ref().and_then(|tail| tail.tail.as_ref().and_then(|tail| tail.ty.as_ref())) {
-			Ok(lir)
-		} else {
-			Err(LIRError { ty: LIRErrorType::MismatchedTypes })
+		let false_diverges = false_item.as_ref().map(|b| b.diverges()).unwrap_or(false);
+		if false_diverges {
+			name_resolve.init = init_after_true;
+		} else if !true_block.diverges() {
+			name_resolve.init.merge(init_after_true);
+		}
+		if false_item.is_none() && true_block.tail.as_ref().and_then(|tail| tail.ty.as_ref()).is_some() {
+			Err(LIRError { ty: LIRErrorType::IfMissingElse, span: None })?;
+		}
+		let mut lir = If(Box::new(condition), Box::new(true_block), false_item);
+		unify_branches(std::iter::once(&mut *lir.1).chain(lir.2.as_deref_mut()).collect(), &name_resolve.target)?;
+		Ok(lir)
+	}
+
+	/// The type this if expression evaluates to. A diverging branch takes on the type of the other one, and if both
+	/// diverge, so does the if.
+	fn ty(&self) -> Option<Type> {
+		let block_ty = |b: &Block| if b.diverges() { Some(Type::Never) } else { b.tail.as_ref().and_then(|e| e.ty.clone()) };
+		let true_ty = block_ty(&self.1);
+		match self.2.as_ref().map(|b| block_ty(b)) {
+			Some(Some(Type::Never)) => true_ty,
+			Some(false_ty) if true_ty == Some(Type::Never) => false_ty,
+			Some(_) => true_ty,
+			// Without an else, the if completes whenever the condition is false
+			None if true_ty == Some(Type::Never) => None,
+			None => true_ty,
 		}
 	}
 }
 
 impl Type {
+	/// Lower a function's return type. Returning `()` is the same as returning nothing, so it becomes `None`.
+	fn return_from_ast(ast: Option<ast::Type>, name_resolve: &mut NameResolveMap) -> Result<Option<Type>, LIRError> {
+		Ok(ast.map(|t| Type::from_ast(t, name_resolve)).transpose()?.filter(|t| !t.is_unit()))
+	}
+
 	fn from_ast(ast: ast::Type, name_resolve: &mut NameResolveMap) -> Result<Type, LIRError> {
 		Ok(match ast {
-			ast::Type::Name(v) => if v.len() == 1 {
+			ast::Type::Name(v, span) => if v.len() == 1 {
 				match &*v[0] {
 					"bool" => Type::Primitive(Primitive::Bool),
 					"i8" => Type::Primitive(Primitive::I8),
 					"i16" => Type::Primitive(Primitive::I16),
 					"i32" => Type::Primitive(Primitive::I32),
 					"i64" => Type::Primitive(Primitive::I64),
+					"i128" => Type::Primitive(Primitive::I128),
 					"u8" => Type::Primitive(Primitive::U8),
 					"u16" => Type::Primitive(Primitive::U16),
 					"u32" => Type::Primitive(Primitive::U32),
 					"u64" => Type::Primitive(Primitive::U64),
+					"u128" => Type::Primitive(Primitive::U128),
+					"isize" => Type::Primitive(Primitive::ISize),
+					"usize" => Type::Primitive(Primitive::USize),
+					"f32" => Type::Primitive(Primitive::F32),
+					"f64" => Type::Primitive(Primitive::F64),
 					"c_char" => Type::Primitive(Primitive::CChar),
 					"c_short" => Type::Primitive(Primitive::CShort),
 					"c_int" => Type::Primitive(Primitive::CInt),
 					"c_long" => Type::Primitive(Primitive::CLong),
 					"c_longlong" => Type::Primitive(Primitive::CLLong),
-					_ => Type::Name(name_resolve.resolve_typename_default(v).ok_or(LIRError { ty: LIRErrorType::UnresolvedIdent })?)
+					_ => Type::Name(name_resolve.resolve_typename_default(v.clone()).ok_or_else(|| name_resolve.unresolved_typename(&v, span))?)
 				}
 			} else {
-				Type::Name(name_resolve.resolve_typename_default(v).ok_or(LIRError { ty: LIRErrorType::UnresolvedIdent })?)
+				Type::Name(name_resolve.resolve_typename_default(v.clone()).ok_or_else(|| name_resolve.unresolved_typename(&v, span))?)
 			},
 			ast::Type::PtrDynConst(ty) => Type::PtrDynConst(Box::new(Type::from_ast(*ty, name_resolve)?)),
 			ast::Type::PtrDynMut(ty) => Type::PtrDynMut(Box::new(Type::from_ast(*ty, name_resolve)?)),
 			ast::Type::PtrConst(ty) => Type::PtrConst(Box::new(Type::from_ast(*ty, name_resolve)?)),
 			ast::Type::PtrMut(ty) => Type::PtrMut(Box::new(Type::from_ast(*ty, name_resolve)?)),
 			ast::Type::Slice(ty) => Type::Slice(Box::new(Type::from_ast(*ty, name_resolve)?)),
+			// The length has to be indexable by an `isize` on the target, and LLVM array lengths are 32 bits besides
+			ast::Type::Arr(_, n) if n > u32::MAX as u64 || !Primitive::ISize.can_hold(n as i128, &name_resolve.target) => Err(LIRError { ty: LIRErrorType::ArrayTooLarge, span: None })?,
 			ast::Type::Arr(ty, n) => Type::Arr(Box::new(Type::from_ast(*ty, name_resolve)?), n),
 			ast::Type::Tuple(types) => Type::Tuple(types.into_iter().map(|ty| Type::from_ast(ty, name_resolve)).collect::<Result<_, _>>()?),
+			ast::Type::Fn(params, returns, varadic) => Type::Fn(
+				params.into_iter().map(|ty| Type::from_ast(ty, name_resolve)).collect::<Result<_, _>>()?,
+				Type::return_from_ast(returns.map(|ty| *ty), name_resolve)?.map(Box::new),
+				varadic,
+			),
 		})
 	}
 }
 
+/// How deeply expressions may be nested unless configured otherwise
+pub const DEFAULT_RECURSION_LIMIT: usize = 512;
+
+/// The widths, in bits, of the integer types whose size depends on the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetWidths {
+	/// The width of a pointer, and so of `isize` and `usize`
+	pub pointer: u32,
+	/// The width of C's `long`
+	pub c_long: u32,
+}
+
+impl Default for TargetWidths {
+	/// LP64, as on 64-bit Unix
+	fn default() -> TargetWidths {
+		TargetWidths {
+			pointer: 64,
+			c_long: 64,
+		}
+	}
+}
+
+/// Names of the built-in types, as written in source, in the same order as [`Primitive`]'s variants
+pub const PRIMITIVE_NAMES: [&str; 20] = [
+	"bool", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "isize", "usize", "f32", "f64",
+	"c_char", "c_short", "c_int", "c_long", "c_longlong",
+];
+
+impl std::fmt::Display for Primitive {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(PRIMITIVE_NAMES[self.clone() as usize])
+	}
+}
+
+impl std::fmt::Display for Type {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Type::Never => write!(f, "!"),
+			Type::Primitive(p) => write!(f, "{}", p),
+			Type::Name(n) => write!(f, "{}", n),
+			Type::PtrConst(t) => write!(f, "*const {}", t),
+			Type::PtrMut(t) => write!(f, "*mut {}", t),
+			Type::PtrDynConst(t) => write!(f, "*dyn const {}", t),
+			Type::PtrDynMut(t) => write!(f, "*dyn mut {}", t),
+			Type::Slice(t) => write!(f, "[{}]", t),
+			Type::Arr(t, n) => write!(f, "[{}; {}]", t, n),
+			Type::Tuple(types) => write!(f, "({})", types.iter().map(Type::to_string).collect::<Vec<_>>().join(", ")),
+			Type::Fn(params, returns, varadic) => {
+				write!(f, "fn({}{})", params.iter().map(Type::to_string).collect::<Vec<_>>().join(", "), if *varadic { "..." } else { "" })?;
+				match returns {
+					Some(returns) => write!(f, " -> {}", returns),
+					None => Ok(()),
+				}
+			},
+		}
+	}
+}
+
+/// Shows the identifier as it was written in source
+impl std::fmt::Display for Ident {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			// Locals are suffixed to keep shadowed bindings apart; the suffix isn't part of the name
+			Ident::Local(s) => f.write_str(s.split('.').next().unwrap_or(s)),
+			_ => f.write_str(&self.source_name()),
+		}
+	}
+}
+
 impl Ident {
 	pub fn fn_mangle(&self) -> String {
 		match self {
 			Ident::UnmangledItem(s) => s.clone(),
-			// Ident::Function(parts) => std::iter::once("_LZ".to_owned())
-			// 	.chain(
-			// 		parts.iter()
-			// 			.flat_map(|item|
-			// 				std::iter::once(item.len().to_string())
-			// 					.chain(std::iter::once(item.clone()))
-			// 			)
-			// 	)
-			// 	.chain(std::iter::once("E".to_owned()))
-			// 	.collect(),
+			Ident::Function(parts) => std::iter::once("_LZ".to_owned())
+				.chain(
+					parts.iter()
+						.flat_map(|item|
+							std::iter::once(item.len().to_string())
+								.chain(std::iter::once(item.clone()))
+						)
+				)
+				.chain(std::iter::once("E".to_owned()))
+				.collect(),
 			_ => panic!("Attempted to mangle incompatible id as function"),
 		}
 	}
 
+	/// The name this identifier was written as in Lok source, with path segments joined by `::`
+	pub fn source_name(&self) -> String {
+		match self {
+			Ident::Local(s) | Ident::UnmangledItem(s) => s.clone(),
+			Ident::Function(parts) => parts.join("::"),
+		}
+	}
+
 	pub fn mod_mangle(&self) -> String {
 		"TODO".to_owned() //TODO
 	}
@@ -561,11 +1337,173 @@ impl Ident {
 	}
 }
 
+/// Heap strings are allocated by calling `malloc`, so a program can only declare its own if it's the C one
+fn check_malloc(name_resolve: &NameResolveMap) -> Result<(), LIRError> {
+	match name_resolve.local_fns.get(&Ident::UnmangledItem("malloc".to_owned())) {
+		Some(decl) if !decl.allocates_bytes() => Err(LIRError { ty: LIRErrorType::IncompatibleMalloc, span: None }),
+		_ => Ok(()),
+	}
+}
+
+/// The identifier a Lok function definition is compiled under
+fn fn_def_ident(f: &ast::FnDef) -> Ident {
+	if f.export {
+		Ident::UnmangledItem(f.name.clone())
+	} else {
+		Ident::Function(vec![f.name.clone()]) //TODO: Module path
+	}
+}
+
+/// Give the branches of an `if` or `match` a common type. Branches that diverge fit any type, and a literal tail takes
+/// the type of the other branches. Returns `None` if every branch diverges, otherwise the type, like `LoopBreak::ty`.
+fn unify_branches(mut blocks: Vec<&mut Block>, target: &TargetWidths) -> Result<Option<Option<Type>>, LIRError> {
+	let ty = match blocks.iter().filter(|b| !b.diverges()).min_by_key(|b| b.tail.as_ref().map(Expression::is_literal).unwrap_or(false)) {
+		Some(b) => b.tail.as_ref().and_then(|e| e.ty.clone()),
+		None => return Ok(None),
+	};
+	for block in blocks.iter_mut().filter(|b| !b.diverges()) {
+		block.tail = match (block.tail.take(), &ty) {
+			(Some(tail), Some(ty)) => Some(tail.coerce_literal(ty, target).ok_or(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?),
+			(tail, None) if tail.as_ref().and_then(|e| e.ty.as_ref()).is_none() => tail,
+			_ => Err(LIRError { ty: LIRErrorType::MismatchedTypes, span: None })?,
+		};
+	}
+	Ok(Some(ty))
+}
+
 struct LoopBreak {
-	name: String,
+	name: String, // Empty for unlabeled loops
 	ty: Option<Option<Type>>, // Outer option is assignment, inner option is for void or not
+	/// Initialization state merged over every break, `None` if nothing breaks out yet
+	exits: Option<InitState>,
+	/// Initialization state merged over every path going back to the start of the loop
+	repeats: InitState,
+}
+
+/// An integer literal as written. Its sign and magnitude are kept apart so that it can be anything from the most
+/// negative `i128` to the largest `u128`.
+#[derive(Debug, Clone)]
+pub struct IntLiteral {
+	pub negative: bool,
+	pub magnitude: u128,
+	pub suffix: Option<Primitive>,
+	/// The literal's source text, suffix and all, for error messages
+	pub text: String,
+}
+
+impl IntLiteral {
+	/// The literal's value, if it's in the range of an `i128`
+	pub fn to_i128(&self) -> Option<i128> {
+		if self.negative {
+			if self.magnitude > i128::MAX as u128 + 1 {
+				None
+			} else {
+				Some((self.magnitude as i128).wrapping_neg())
+			}
+		} else {
+			i128::try_from(self.magnitude).ok()
+		}
+	}
+
+	/// The literal's value as a two's complement bit pattern
+	pub fn bits(&self) -> u128 {
+		if self.negative { self.magnitude.wrapping_neg() } else { self.magnitude }
+	}
+
+	/// Whether the literal can be given this type. Only `u128` can hold values past the end of `i128`'s range.
+	fn fits(&self, primitive: &Primitive, target: &TargetWidths) -> bool {
+		match self.to_i128() {
+			Some(value) => primitive.can_hold(value, target),
+			None => !self.negative && *primitive == Primitive::U128,
+		}
+	}
 }
 
-fn integer_type_for_value(_value: u64) -> Type {
-	Type::Primitive(Primitive::I32) //TODO
+/// Parse the text of an integer literal, honouring a leading `-`, the `0x`, `0o` and `0b` radix prefixes, `_` digit
+/// separators and a type suffix such as `u8`. Returns `None` if the literal is malformed or its magnitude doesn't fit
+/// in a `u128`; whether it fits its type is left to the LIR.
+pub fn parse_integer_literal(text: &str) -> Option<IntLiteral> {
+	let full_text = text;
+	let (text, suffix) = split_integer_suffix(text);
+	let (negative, text) = match text.strip_prefix('-') {
+		Some(magnitude) => (true, magnitude),
+		None => (false, text),
+	};
+	let (radix, digits) = match text.get(0..2) {
+		Some("0x") | Some("0X") => (16, &text[2..]),
+		Some("0o") => (8, &text[2..]),
+		Some("0b") => (2, &text[2..]),
+		_ => (10, text),
+	};
+	let magnitude = u128::from_str_radix(&strip_digit_separators(digits)?, radix).ok()?;
+	Some(IntLiteral {
+		negative,
+		magnitude,
+		suffix,
+		text: full_text.to_owned(),
+	})
+}
+
+/// Parse the text of a float literal, such as `1_000.5`. Returns `None` if a digit separator is misplaced.
+pub fn parse_float_literal(text: &str) -> Option<f64> {
+	let (whole, fraction) = text.split_at(text.find('.')?);
+	format!("{}.{}", strip_digit_separators(whole)?, strip_digit_separators(&fraction[1..])?).parse().ok()
+}
+
+/// Parse the length in an array type such as `[u8; 0x10]`, which is written like an integer literal but can't be
+/// negative or have a type suffix
+pub fn parse_array_length(text: &str) -> Option<u64> {
+	let literal = parse_integer_literal(text)?;
+	match literal.suffix {
+		None => u64::try_from(literal.to_i128()?).ok(),
+		Some(_) => None,
+	}
+}
+
+/// Split a type suffix off the end of an integer literal. None of the suffixes are hex digits, so this can't eat into
+/// the literal itself.
+fn split_integer_suffix(text: &str) -> (&str, Option<Primitive>) {
+	const SUFFIXES: [(&str, Primitive); 12] = [
+		("i8", Primitive::I8),
+		("i16", Primitive::I16),
+		("i32", Primitive::I32),
+		("i64", Primitive::I64),
+		("i128", Primitive::I128),
+		("u8", Primitive::U8),
+		("u16", Primitive::U16),
+		("u32", Primitive::U32),
+		("u64", Primitive::U64),
+		("u128", Primitive::U128),
+		("isize", Primitive::ISize),
+		("usize", Primitive::USize),
+	];
+	for (suffix, primitive) in SUFFIXES.iter() {
+		if let Some(literal) = text.strip_suffix(suffix) {
+			return (literal, Some(primitive.clone()));
+		}
+	}
+	(text, None)
+}
+
+/// Remove `_` separators from a run of digits. Separators may only appear singly and between two digits.
+fn strip_digit_separators(digits: &str) -> Option<String> {
+	if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+		None
+	} else {
+		Some(digits.replace('_', ""))
+	}
+}
+
+/// Pick the type of an unsuffixed integer literal, or `None` if it's past the end of `i128`'s range. Such a literal
+/// can only be a `u128`, which has to be asked for with a suffix.
+fn integer_type_for_literal(literal: &IntLiteral, target: &TargetWidths) -> Option<Type> {
+	// Negative literals carry their sign, so the signed range decides the width
+	let value = literal.to_i128()?;
+	if Primitive::I32.can_hold(value, target) {
+		Some(Type::Primitive(Primitive::I32))
+	} else if Primitive::I64.can_hold(value, target) {
+		Some(Type::Primitive(Primitive::I64))
+	} else {
+		Some(Type::Primitive(Primitive::I128))
+	}
 }
@@ -2,17 +2,230 @@
 
 #[derive(Debug)]
 pub struct LIRError {
-	pub ty: LIRErrorType
+	pub ty: LIRErrorType,
+	/// Byte offsets of the code at fault, if it's known
+	pub span: Option<(usize, usize)>,
 }
 
 #[derive(Debug)]
 pub enum LIRErrorType {
-	UnresolvedIdent,
+	/// A name that doesn't refer to anything in scope. Contains the name and a similar name that does, if any.
+	UnresolvedIdent(String, Option<String>),
 	MismatchedTypes,
+	InvalidOperandType,
 	ArgCountMismatch,
+	ConflictingDeclaration,
 	VoidValue,
 	InvalidLValueExpr,
+	/// An assignment to a name that refers to a function or type. Contains the name.
+	AssignToNonVariable(String),
+	NoSuchField,
 	ImmutAssign,
-	IllegalConditionExpr,
+	/// Contains the type the condition had
+	IllegalConditionExpr(String),
+	IfMissingElse,
 	BreakOutsideLoop,
-}
\ No newline at end of file
+	ContinueOutsideLoop,
+	/// A statement after a `return`, `break`, `continue`, or other expression that never completes
+	UnreachableStatement,
+	/// A `break` to a label that no enclosing loop has. Contains the label.
+	UndefinedLabel(String),
+	NoEntryPoint,
+	MultipleEntryPoints,
+	InvalidMainSignature,
+	/// An integer literal out of range of its suffix type, or too large for any integer type. Contains the literal.
+	IntegerOverflow(String),
+	UseBeforeInit,
+	ArrayTooLarge,
+	/// A `match` without a `_` arm
+	NonExhaustiveMatch,
+	DefinitionInDeclarations,
+	InvalidCast,
+	RecursionLimitExceeded,
+	/// A heap string literal in a program whose `malloc` doesn't have C's signature
+	IncompatibleMalloc,
+}
+
+impl std::fmt::Display for LIRErrorType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			LIRErrorType::UnresolvedIdent(name, None) => write!(f, "unresolved identifier `{}`", name),
+			LIRErrorType::UnresolvedIdent(name, Some(suggestion)) => write!(f, "unresolved identifier `{}`, did you mean `{}`?", name, suggestion),
+			LIRErrorType::MismatchedTypes => write!(f, "mismatched types"),
+			LIRErrorType::InvalidOperandType => write!(f, "invalid operand type for operator"),
+			LIRErrorType::ArgCountMismatch => write!(f, "wrong number of arguments to function"),
+			LIRErrorType::ConflictingDeclaration => write!(f, "declaration conflicts with an earlier one"),
+			LIRErrorType::VoidValue => write!(f, "expression has no value"),
+			LIRErrorType::InvalidLValueExpr => write!(f, "expression cannot be assigned to"),
+			LIRErrorType::AssignToNonVariable(name) => write!(f, "cannot assign to `{}`, which is not a variable", name),
+			LIRErrorType::NoSuchField => write!(f, "no such field"),
+			LIRErrorType::ImmutAssign => write!(f, "cannot assign to immutable variable"),
+			LIRErrorType::IllegalConditionExpr(found) => write!(f, "condition must be a `bool`, found `{}`; integers can be tested with `as bool`", found),
+			LIRErrorType::IfMissingElse => write!(f, "if expression with a value has no else branch"),
+			LIRErrorType::BreakOutsideLoop => write!(f, "break outside of a loop"),
+			LIRErrorType::ContinueOutsideLoop => write!(f, "continue outside of a loop"),
+			LIRErrorType::UnreachableStatement => write!(f, "statement can never be reached"),
+			LIRErrorType::UndefinedLabel(label) => write!(f, "no enclosing loop is labeled `'{}`", label),
+			LIRErrorType::NoEntryPoint => write!(f, "no entry point or function definitions"),
+			LIRErrorType::MultipleEntryPoints => write!(f, "multiple entry points declared"),
+			LIRErrorType::InvalidMainSignature => write!(f, "`main` must take no parameters and return `c_int` or nothing"),
+			LIRErrorType::IntegerOverflow(literal) => write!(f, "integer literal {} is out of range for its type", literal),
+			LIRErrorType::UseBeforeInit => write!(f, "variable used before being assigned a value"),
+			LIRErrorType::ArrayTooLarge => write!(f, "array length is too large for the target"),
+			LIRErrorType::NonExhaustiveMatch => write!(f, "match is not exhaustive; add a `_` arm"),
+			LIRErrorType::InvalidCast => write!(f, "invalid cast"),
+			LIRErrorType::RecursionLimitExceeded => write!(f, "expression is nested too deeply"),
+			LIRErrorType::IncompatibleMalloc => write!(f, "heap strings need `malloc` to take a `usize` and return a byte pointer, but it's declared differently"),
+			LIRErrorType::DefinitionInDeclarations => write!(f, "only `extern` declarations are allowed when compiling declarations only"),
+		}
+	}
+}
+
+/// Why a [`Compiler`](crate::codegen::Compiler) couldn't be set up to generate code for a target
+#[derive(Debug)]
+pub enum TargetError {
+	/// The triple doesn't have the parts needed to tell which C ABI to use. Contains the triple.
+	MalformedTriple(String),
+	/// LLVM has no backend for the triple. Contains the triple and LLVM's message.
+	UnsupportedTarget(String, String),
+	/// LLVM couldn't create a target machine. Contains the triple, CPU, and features requested.
+	MachineCreationFailed(String, String, String),
+}
+
+impl std::fmt::Display for TargetError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TargetError::MalformedTriple(triple) => write!(f, "target {} is not a valid triple; expected at least an architecture and an operating system, such as x86_64-unknown-linux-gnu", triple),
+			TargetError::UnsupportedTarget(triple, message) => write!(f, "target {} is not supported by this build of LLVM: {}", triple, message),
+			TargetError::MachineCreationFailed(triple, cpu, features) => write!(f, "could not create a target machine for {} (cpu {}, features \"{}\")", triple, cpu, features),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+	Error,
+	Warning,
+}
+
+/// A problem found anywhere in the compiler, from opening the file through to lowering it to LIR
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub severity: Severity,
+	pub message: String,
+	/// Byte offsets into the source file, if the problem can be pinned to a location
+	pub span: Option<(usize, usize)>,
+}
+
+impl Diagnostic {
+	pub fn error(message: impl Into<String>, span: Option<(usize, usize)>) -> Diagnostic {
+		Diagnostic {
+			severity: Severity::Error,
+			message: message.into(),
+			span,
+		}
+	}
+
+	pub fn warning(message: impl Into<String>, span: Option<(usize, usize)>) -> Diagnostic {
+		Diagnostic {
+			severity: Severity::Warning,
+			message: message.into(),
+			span,
+		}
+	}
+}
+
+impl Diagnostic {
+	/// Display this diagnostic prefixed with the file, line and column its span starts at
+	pub fn located<'a>(&'a self, source_map: &'a crate::source::SourceMap) -> impl std::fmt::Display + 'a {
+		Located(self, source_map)
+	}
+}
+
+struct Located<'a>(&'a Diagnostic, &'a crate::source::SourceMap);
+
+impl std::fmt::Display for Located<'_> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let Located(diagnostic, source_map) = self;
+		match diagnostic.span {
+			Some((start, _)) => {
+				let (line, column) = source_map.locate(start);
+				write!(f, "{}:{}:{}: {}", source_map.name(), line, column, Unlocated(diagnostic))
+			},
+			None => write!(f, "{}: {}", source_map.name(), diagnostic),
+		}
+	}
+}
+
+/// A diagnostic without the raw byte offsets, once they've been replaced with a line and column
+struct Unlocated<'a>(&'a Diagnostic);
+
+impl std::fmt::Display for Unlocated<'_> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.0.severity {
+			Severity::Error => write!(f, "error: ")?,
+			Severity::Warning => write!(f, "warning: ")?,
+		}
+		write!(f, "{}", self.0.message)
+	}
+}
+
+impl std::fmt::Display for Diagnostic {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", Unlocated(self))?;
+		if let Some((start, end)) = self.span {
+			write!(f, " (at {}..{})", start, end)?;
+		}
+		Ok(())
+	}
+}
+
+impl From<std::io::Error> for Diagnostic {
+	fn from(e: std::io::Error) -> Diagnostic {
+		Diagnostic::error(e.to_string(), None)
+	}
+}
+
+impl From<TargetError> for Diagnostic {
+	fn from(e: TargetError) -> Diagnostic {
+		Diagnostic::error(e.to_string(), None)
+	}
+}
+
+impl From<crate::LexError> for Diagnostic {
+	fn from(e: crate::LexError) -> Diagnostic {
+		let span = match e {
+			crate::LexError::InvalidEscape(offset) => Some((offset, offset + 2)),
+			_ => None,
+		};
+		let message = match e {
+			crate::LexError::StringTooLong => "string literal is too long".to_owned(),
+			crate::LexError::InvalidIntegerLiteral(literal) => format!("invalid integer literal {}", literal),
+			crate::LexError::InvalidFloatLiteral(literal) => format!("invalid float literal {}", literal),
+			crate::LexError::UnterminatedString => "unterminated string literal".to_owned(),
+			crate::LexError::UnterminatedComment => "unterminated block comment".to_owned(),
+			crate::LexError::InvalidEscape(_) => "invalid escape sequence in string literal".to_owned(),
+			crate::LexError::InvalidCharLiteral(text) => format!("character literal '{}' must contain exactly one character", text),
+		};
+		Diagnostic::error(message, span)
+	}
+}
+
+impl From<lalrpop_util::ParseError<usize, crate::lexer::Token, crate::LexError>> for Diagnostic {
+	fn from(e: lalrpop_util::ParseError<usize, crate::lexer::Token, crate::LexError>) -> Diagnostic {
+		use lalrpop_util::ParseError;
+		match e {
+			ParseError::InvalidToken { location } => Diagnostic::error("invalid token", Some((location, location))),
+			ParseError::UnrecognizedEOF { location, expected } => Diagnostic::error(format!("unexpected end of file, expected one of {}", expected.join(", ")), Some((location, location))),
+			ParseError::UnrecognizedToken { token: (start, token, end), expected } => Diagnostic::error(format!("unexpected {:?}, expected one of {}", token, expected.join(", ")), Some((start, end))),
+			ParseError::ExtraToken { token: (start, token, end) } => Diagnostic::error(format!("unexpected {:?}", token), Some((start, end))),
+			ParseError::User { error } => error.into(),
+		}
+	}
+}
+
+impl From<LIRError> for Diagnostic {
+	fn from(e: LIRError) -> Diagnostic {
+		Diagnostic::error(e.ty.to_string(), e.span)
+	}
+}
@@ -1,7788 +0,0 @@
-// auto-generated: "lalrpop 0.19.5"
-// sha3: 6fffb104d1c1df3b786eeae52b824607fa3aabab6ba3ba19fc618b28d53a1
-use either::{Either, Left, Right};
-use crate::LexError;
-use crate::lexer;
-use crate::codegen::ast;
-#[allow(unused_extern_crates)]
-extern crate lalrpop_util as __lalrpop_util;
-#[allow(unused_imports)]
-use self::__lalrpop_util::state_machine as __state_machine;
-extern crate core;
-extern crate alloc;
-
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__LokFile {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
-
-    use either::{Either, Left, Right};
-    use crate::LexError;
-    use crate::lexer;
-    use crate::codegen::ast;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    use super::__ToTriple;
-    #[allow(dead_code)]
-    pub(crate) enum __Symbol<>
-     {
-        Variant0(lexer::Token),
-        Variant1(Vec<u8>),
-        Variant2(String),
-        Variant3(core::option::Option<lexer::Token>),
-        Variant4(core::option::Option<Vec<u8>>),
-        Variant5(ast::Type),
-        Variant6(core::option::Option<ast::Type>),
-        Variant7((String, ast::Type)),
-        Variant8(core::option::Option<(String, ast::Type)>),
-        Variant9((core::option::Option<String>, ast::Type)),
-        Variant10(core::option::Option<(core::option::Option<String>, ast::Type)>),
-        Variant11(alloc::vec::Vec<(String, ast::Type)>),
-        Variant12(alloc::vec::Vec<(core::option::Option<String>, ast::Type)>),
-        Variant13(ast::Expression),
-        Variant14(alloc::vec::Vec<ast::Expression>),
-        Variant15(core::option::Option<String>),
-        Variant16(alloc::vec::Vec<String>),
-        Variant17(ast::Block),
-        Variant18(Vec<(String, ast::Type)>),
-        Variant19(Vec<(core::option::Option<String>, ast::Type)>),
-        Variant20(Vec<ast::Expression>),
-        Variant21(ast::Decl),
-        Variant22(ast::Def),
-        Variant23(core::option::Option<ast::Expression>),
-        Variant24(Vec<ast::TopLevelDecl>),
-        Variant25(ast::NSIdent),
-        Variant26(ast::Statement),
-        Variant27(alloc::vec::Vec<ast::Statement>),
-        Variant28(Either<Box<ast::If>, Box<ast::Block>>),
-        Variant29(ast::If),
-        Variant30(core::option::Option<Either<Box<ast::If>, Box<ast::Block>>>),
-        Variant31(ast::TopLevelDecl),
-        Variant32(alloc::vec::Vec<ast::TopLevelDecl>),
-        Variant33(ast::TopLevelDef),
-        Variant34(alloc::vec::Vec<ast::Type>),
-    }
-    const __ACTION: &[i16] = &[
-        // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 69, 70, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 69, 70, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 4
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 100, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 7, 110, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 7
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 8
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 118, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 9
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 10
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 11
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 14
-        0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0,
-        // State 15
-        0, 7, 134, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 16
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 17
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 18
-        0, 10, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 19
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 20
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 21
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 22
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 23
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 24
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 25
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 26
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 27
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 28
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 29
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 30
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 31
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 100, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 34
-        0, 7, -64, 83, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0,
-        // State 35
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 36
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 37
-        0, 10, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, 50, 0, 0, 0, -126, -126, -126, 0, -126, 0, 0, 0, -126, -126, 0, 0, 0, -126, 0, 0, -126, -126, 0,
-        // State 42
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 43
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 44
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 12, 99, 13, 0, 14, 0, 0, 0, 5, 118, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 45
-        0, 7, -65, 83, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0,
-        // State 46
-        0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 51
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 52
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 53
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 54
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 55
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 56
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 57
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 58
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 61
-        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 13, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 101, 0, 0, 84, 102, 0,
-        // State 62
-        0, 7, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
-        // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, -132, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, -139, -139, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 65
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 66
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, -135, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 67
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, -131, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 69
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0,
-        // State 70
-        0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80,
-        // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, -136, -136, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, -138, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, -53, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0,
-        // State 76
-        0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105,
-        // State 77
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106,
-        // State 79
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, -79, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0,
-        // State 81
-        0, -140, -140, -140, 0, -140, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, 0, 0, 0, 0, -140, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0,
-        // State 82
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 111, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 83
-        -94, -94, -94, -94, -94, -94, -94, 0, 0, -94, -94, 0, 113, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, 0, 0, 0, 0, 0, 0, -94, 0, 0,
-        // State 84
-        0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 85
-        -88, 19, -88, -88, -88, -88, -88, 0, 0, 0, -88, 0, 0, -88, -88, -88, 0, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 86
-        0, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 20, 21, 0, 22, 0, 23, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 88
-        25, 0, -113, 26, -113, -113, -113, 0, 0, 0, 27, 0, 0, -113, -113, -113, 0, -113, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 89
-        -154, -154, -154, -154, -154, -154, -154, 0, 0, 0, -154, 0, 0, -154, -154, -154, 28, -154, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 90
-        -91, -91, -91, -91, -91, -91, -91, 0, 0, 0, -91, 0, 0, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 91
-        0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, -109, -109, -109, 0, -109, 0, 0, 0, -109, -109, 0, 0, 0, -109, 0, 0, -109, -109, 0,
-        // State 92
-        0, 0, -75, 0, 29, -75, 30, 0, 0, 0, 0, 0, 0, -75, -75, -75, 0, -75, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 93
-        -89, -89, -89, -89, -89, -89, -89, 0, 0, 0, -89, 0, 0, -89, -89, -89, -89, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 94
-        -90, -90, -90, -90, -90, -90, -90, 0, 0, 0, -90, 0, 0, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 95
-        0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, -96, 0, 0, 0, -96, -96, 0, 0, 0, -96, 0, 0, -96, -96, 0,
-        // State 96
-        0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, -97, 0, 0, 0, -97, -97, 0, 0, 0, -97, 0, 0, -97, -97, 0,
-        // State 97
-        -55, -55, -55, -55, -55, -55, -55, 0, 0, 0, -55, 0, 0, -55, -55, -55, 0, -55, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0,
-        // State 99
-        -119, -119, -119, -119, -119, -119, -119, 0, 0, 0, -119, 0, 0, -119, -119, -119, 0, -119, 0, -119, -119, 0, 0, -119, 0, 0, -119, -119, -119, -119, -119, -119, -119, 0, -119, 0, -119, 0, -119, -119, 0, 0, 0, -119, 0, 0, -119, -119, 0,
-        // State 100
-        -155, -155, -155, -155, -155, -155, -155, 0, 0, 0, -155, 0, 0, -155, -155, -155, 0, -155, 0, -155, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 101
-        -153, -153, -153, -153, -153, -153, -153, 0, 0, 0, -153, 0, 0, -153, -153, -153, 0, -153, 0, -153, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 102
-        0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127,
-        // State 104
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 105
-        0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 106
-        -95, -95, -95, -95, -95, -95, -95, 0, 0, -95, -95, 0, 132, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, 0, 0, 0, 0, 0, 0, -95, 0, 0,
-        // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, -137, -137, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 108
-        0, -151, -151, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, 0,
-        // State 109
-        0, -147, -147, -147, 0, -147, 0, 0, 0, -147, 0, 0, 0, -147, 0, 0, -147, 0, 0, 0, 0, -147, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0,
-        // State 110
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 111
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 112
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0,
-        // State 113
-        0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, -98, 0, 0, 0, -98, -98, 0, 0, 0, -98, 0, 0, -98, -98, 0,
-        // State 114
-        -114, -114, -114, -114, -114, -114, -114, 0, 0, 0, -114, 0, 0, -114, -114, -114, -114, -114, 0, -114, -114, 0, 0, 0, 0, 0, -114, -114, -114, -114, 0, 0, 0, 0, 0, 0, -114, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 116
-        0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, -110, -110, -110, 0, -110, 0, 0, 0, -110, -110, 0, 0, 0, -110, 0, 0, -110, -110, 0,
-        // State 117
-        -120, -120, -120, -120, -120, -120, -120, 0, 0, 0, -120, 0, 0, -120, -120, -120, 0, -120, 0, -120, -120, 0, 0, -120, 0, 0, -120, -120, -120, -120, -120, -120, -120, 0, -120, 0, -120, 0, -120, -120, 0, 0, 0, -120, 0, 0, -120, -120, 0,
-        // State 118
-        0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 119
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 120
-        0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, -104, -104, -104, 0, -104, 0, 0, 0, -104, -104, 0, 0, 0, -104, 0, 0, -104, -104, 0,
-        // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0,
-        // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 123
-        -156, -156, -156, -156, -156, -156, -156, 0, 0, 0, -156, 0, 0, -156, -156, -156, 0, -156, 0, -156, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -156, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 124
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 125
-        0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, -106, -106, -106, 0, -106, 0, 0, 0, -106, -106, 0, 0, 0, -106, 0, 0, -106, -106, 0,
-        // State 126
-        0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 127
-        0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0,
-        // State 128
-        0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 129
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 130
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164,
-        // State 131
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0,
-        // State 132
-        0, -152, -152, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0,
-        // State 133
-        0, -148, -148, -148, 0, -148, 0, 0, 0, -148, 0, 0, 0, -148, 0, 0, -148, 0, 0, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0,
-        // State 134
-        0, -141, -141, -141, 0, -141, 0, 0, 0, -141, 0, 0, 0, -141, 0, 0, -141, 0, 0, 0, 0, -141, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0,
-        // State 135
-        0, -142, -142, -142, 0, -142, 0, 0, 0, -142, 0, 0, 0, -142, 0, 0, -142, 0, 0, 0, 0, -142, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0,
-        // State 136
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0,
-        // State 137
-        0, -145, -145, -145, 0, -145, 0, 0, 0, -145, 0, 0, 0, -145, 0, 0, -145, 0, 0, 0, 0, -145, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0,
-        // State 138
-        0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 139
-        0, 0, -66, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 140
-        -154, -154, -154, -154, -154, -154, -154, 0, 0, 0, -154, 0, 0, -154, -154, -154, 0, -154, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 141
-        0, 0, -70, 0, 29, -70, 30, 0, 0, 0, 0, 0, 0, -70, -70, -70, 0, -70, 0, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 142
-        0, 0, -71, 0, 29, -71, 30, 0, 0, 0, 0, 0, 0, -71, -71, -71, 0, -71, 0, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 143
-        0, 0, -72, 0, 29, -72, 30, 0, 0, 0, 0, 0, 0, -72, -72, -72, 0, -72, 0, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 144
-        0, 0, -74, 0, 29, -74, 30, 0, 0, 0, 0, 0, 0, -74, -74, -74, 0, -74, 0, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 145
-        0, 0, -73, 0, 29, -73, 30, 0, 0, 0, 0, 0, 0, -73, -73, -73, 0, -73, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 146
-        -87, 19, -87, -87, -87, -87, -87, 0, 0, 0, -87, 0, 0, -87, -87, -87, 0, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 147
-        -85, 19, -85, -85, -85, -85, -85, 0, 0, 0, -85, 0, 0, -85, -85, -85, 0, -85, 0, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 148
-        -86, 19, -86, -86, -86, -86, -86, 0, 0, 0, -86, 0, 0, -86, -86, -86, 0, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 149
-        0, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, -50, 20, 21, 0, 22, 0, 23, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 150
-        -115, -115, -115, -115, -115, -115, -115, 0, 0, 0, -115, 0, 0, -115, -115, -115, -115, -115, 0, -115, -115, 0, 0, 0, 0, 0, -115, -115, -115, -115, 0, 0, 0, 0, 0, 0, -115, 0, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 151
-        25, 0, -111, 26, -111, -111, -111, 0, 0, 0, 27, 0, 0, -111, -111, -111, 0, -111, 0, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 152
-        25, 0, -112, 26, -112, -112, -112, 0, 0, 0, 27, 0, 0, -112, -112, -112, 0, -112, 0, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 153
-        -157, -157, -157, -157, -157, -157, -157, 0, 0, 0, -157, 0, 0, -157, -157, -157, 0, -157, 0, -157, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -157, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 154
-        0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, -103, -103, -103, 0, -103, 0, 0, 0, -103, -103, 0, 0, 0, -103, 0, 0, -103, -103, 0,
-        // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 157
-        0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, -105, -105, -105, 0, -105, 0, 0, 0, -105, -105, 0, 0, 0, -105, 0, 0, -105, -105, 0,
-        // State 158
-        0, 0, 177, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 159
-        0, 0, -62, 0, 0, 179, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 160
-        0, 0, -94, 0, 0, -94, 0, 0, 0, -94, 0, 53, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180,
-        // State 162
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 164
-        0, -143, -143, -143, 0, -143, 0, 0, 0, -143, 0, 0, 0, -143, 0, 0, -143, 0, 0, 0, 0, -143, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0,
-        // State 165
-        0, -144, -144, -144, 0, -144, 0, 0, 0, -144, 0, 0, 0, -144, 0, 0, -144, 0, 0, 0, 0, -144, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0,
-        // State 166
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 167
-        0, 0, -68, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 168
-        -54, -54, -54, -54, -54, -54, -54, 0, 0, 0, -54, 0, 0, -54, -54, -54, 0, -54, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 169
-        0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, -40, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, -40, -40, 0,
-        // State 170
-        -118, -118, -118, -118, -118, -118, -118, 0, 0, 0, -118, 0, 0, -118, -118, -118, -118, -118, 0, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 171
-        0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, -125, -125, -125, 0, -125, 0, 0, 0, -125, -125, 0, 0, 0, -125, 0, 0, -125, -125, 0,
-        // State 172
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 173
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 174
-        0, 0, -63, 0, 0, 192, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 175
-        0, 0, -94, 0, 0, -94, 0, 0, 0, -94, 0, 58, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 176
-        0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 177
-        0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 178
-        0, -34, -34, -34, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0,
-        // State 179
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 180
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, -81, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 181
-        0, 0, -56, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 182
-        0, -146, -146, -146, 0, -146, 0, 0, 0, -146, 0, 0, 0, -146, 0, 0, -146, 0, 0, 0, 0, -146, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0,
-        // State 183
-        0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, -41, -41, 0,
-        // State 184
-        -117, -117, -117, -117, -117, -117, -117, 0, 0, 0, -117, 0, 0, -117, -117, -117, -117, -117, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 185
-        -116, -116, -116, -116, -116, -116, -116, 0, 0, 0, -116, 0, 0, -116, -116, -116, -116, -116, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 186
-        0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, -122, -122, -122, 0, -122, 0, 0, 0, -122, -122, 0, 0, 0, -122, 0, 0, -122, -122, 0,
-        // State 187
-        0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, -121, -121, -121, 0, -121, 0, 0, 0, -121, -121, 0, 0, 0, -121, 0, 0, -121, -121, 0,
-        // State 188
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 189
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 190
-        0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, -102, 0, 0, 0, -102, -102, 0, 0, 0, -102, 0, 0, -102, -102, 0,
-        // State 191
-        0, -36, -36, -36, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0,
-        // State 192
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 193
-        0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 194
-        0, 0, -60, 0, 0, 203, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 195
-        0, 0, -57, 0, 0, 204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 196
-        0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0,
-        // State 197
-        0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, -100, -100, -100, 0, -100, 0, 0, 0, -100, -100, 0, 0, 0, -100, 0, 0, -100, -100, 0,
-        // State 198
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 199
-        0, 0, -61, 0, 0, 208, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 200
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 201
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, -128, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 202
-        0, -33, -33, -33, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0,
-        // State 203
-        0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0,
-        // State 204
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, -80, -80, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 205
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 206
-        0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101, 0, 0, 0, -101, -101, 0, 0, 0, -101, 0, 0, -101, -101, 0,
-        // State 207
-        0, -35, -35, -35, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0,
-        // State 208
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, -129, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 209
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 210
-        0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, -99, -99, -99, 0, -99, 0, 0, 0, -99, -99, 0, 0, 0, -99, 0, 0, -99, -99, 0,
-        // State 211
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, -127, 0, 0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 49 + integer]
-    }
-    const __EOF_ACTION: &[i16] = &[
-        // State 0
-        -92,
-        // State 1
-        -93,
-        // State 2
-        0,
-        // State 3
-        0,
-        // State 4
-        0,
-        // State 5
-        0,
-        // State 6
-        0,
-        // State 7
-        0,
-        // State 8
-        0,
-        // State 9
-        0,
-        // State 10
-        0,
-        // State 11
-        0,
-        // State 12
-        0,
-        // State 13
-        0,
-        // State 14
-        0,
-        // State 15
-        0,
-        // State 16
-        0,
-        // State 17
-        0,
-        // State 18
-        0,
-        // State 19
-        0,
-        // State 20
-        0,
-        // State 21
-        0,
-        // State 22
-        0,
-        // State 23
-        0,
-        // State 24
-        0,
-        // State 25
-        0,
-        // State 26
-        0,
-        // State 27
-        0,
-        // State 28
-        0,
-        // State 29
-        0,
-        // State 30
-        0,
-        // State 31
-        0,
-        // State 32
-        0,
-        // State 33
-        0,
-        // State 34
-        0,
-        // State 35
-        0,
-        // State 36
-        0,
-        // State 37
-        0,
-        // State 38
-        0,
-        // State 39
-        0,
-        // State 40
-        0,
-        // State 41
-        0,
-        // State 42
-        0,
-        // State 43
-        0,
-        // State 44
-        0,
-        // State 45
-        0,
-        // State 46
-        0,
-        // State 47
-        0,
-        // State 48
-        0,
-        // State 49
-        0,
-        // State 50
-        0,
-        // State 51
-        0,
-        // State 52
-        0,
-        // State 53
-        0,
-        // State 54
-        0,
-        // State 55
-        0,
-        // State 56
-        0,
-        // State 57
-        0,
-        // State 58
-        0,
-        // State 59
-        0,
-        // State 60
-        0,
-        // State 61
-        0,
-        // State 62
-        0,
-        // State 63
-        -132,
-        // State 64
-        -139,
-        // State 65
-        -158,
-        // State 66
-        -135,
-        // State 67
-        -131,
-        // State 68
-        0,
-        // State 69
-        0,
-        // State 70
-        0,
-        // State 71
-        -136,
-        // State 72
-        -138,
-        // State 73
-        -53,
-        // State 74
-        -52,
-        // State 75
-        0,
-        // State 76
-        0,
-        // State 77
-        0,
-        // State 78
-        0,
-        // State 79
-        -79,
-        // State 80
-        0,
-        // State 81
-        0,
-        // State 82
-        0,
-        // State 83
-        0,
-        // State 84
-        0,
-        // State 85
-        0,
-        // State 86
-        0,
-        // State 87
-        0,
-        // State 88
-        0,
-        // State 89
-        0,
-        // State 90
-        0,
-        // State 91
-        0,
-        // State 92
-        0,
-        // State 93
-        0,
-        // State 94
-        0,
-        // State 95
-        0,
-        // State 96
-        0,
-        // State 97
-        0,
-        // State 98
-        0,
-        // State 99
-        -119,
-        // State 100
-        0,
-        // State 101
-        0,
-        // State 102
-        0,
-        // State 103
-        0,
-        // State 104
-        -77,
-        // State 105
-        0,
-        // State 106
-        0,
-        // State 107
-        -137,
-        // State 108
-        0,
-        // State 109
-        0,
-        // State 110
-        0,
-        // State 111
-        0,
-        // State 112
-        0,
-        // State 113
-        0,
-        // State 114
-        -114,
-        // State 115
-        0,
-        // State 116
-        0,
-        // State 117
-        -120,
-        // State 118
-        0,
-        // State 119
-        0,
-        // State 120
-        0,
-        // State 121
-        0,
-        // State 122
-        0,
-        // State 123
-        0,
-        // State 124
-        0,
-        // State 125
-        0,
-        // State 126
-        0,
-        // State 127
-        0,
-        // State 128
-        0,
-        // State 129
-        0,
-        // State 130
-        0,
-        // State 131
-        0,
-        // State 132
-        0,
-        // State 133
-        0,
-        // State 134
-        0,
-        // State 135
-        0,
-        // State 136
-        0,
-        // State 137
-        0,
-        // State 138
-        0,
-        // State 139
-        0,
-        // State 140
-        0,
-        // State 141
-        0,
-        // State 142
-        0,
-        // State 143
-        0,
-        // State 144
-        0,
-        // State 145
-        0,
-        // State 146
-        0,
-        // State 147
-        0,
-        // State 148
-        0,
-        // State 149
-        0,
-        // State 150
-        -115,
-        // State 151
-        0,
-        // State 152
-        0,
-        // State 153
-        0,
-        // State 154
-        0,
-        // State 155
-        0,
-        // State 156
-        0,
-        // State 157
-        0,
-        // State 158
-        0,
-        // State 159
-        0,
-        // State 160
-        0,
-        // State 161
-        0,
-        // State 162
-        0,
-        // State 163
-        -78,
-        // State 164
-        0,
-        // State 165
-        0,
-        // State 166
-        0,
-        // State 167
-        0,
-        // State 168
-        0,
-        // State 169
-        0,
-        // State 170
-        0,
-        // State 171
-        0,
-        // State 172
-        0,
-        // State 173
-        0,
-        // State 174
-        0,
-        // State 175
-        0,
-        // State 176
-        0,
-        // State 177
-        0,
-        // State 178
-        0,
-        // State 179
-        -76,
-        // State 180
-        -81,
-        // State 181
-        0,
-        // State 182
-        0,
-        // State 183
-        0,
-        // State 184
-        0,
-        // State 185
-        0,
-        // State 186
-        0,
-        // State 187
-        0,
-        // State 188
-        0,
-        // State 189
-        0,
-        // State 190
-        0,
-        // State 191
-        0,
-        // State 192
-        -130,
-        // State 193
-        0,
-        // State 194
-        0,
-        // State 195
-        0,
-        // State 196
-        0,
-        // State 197
-        0,
-        // State 198
-        0,
-        // State 199
-        0,
-        // State 200
-        0,
-        // State 201
-        -128,
-        // State 202
-        0,
-        // State 203
-        0,
-        // State 204
-        -80,
-        // State 205
-        0,
-        // State 206
-        0,
-        // State 207
-        0,
-        // State 208
-        -129,
-        // State 209
-        0,
-        // State 210
-        0,
-        // State 211
-        -127,
-    ];
-    fn __goto(state: i16, nt: usize) -> i16 {
-        match nt {
-            15 => 127,
-            18 => 45,
-            21 => 37,
-            26 => 80,
-            27 => 84,
-            28 => match state {
-                5 => 107,
-                46 => 180,
-                59 => 204,
-                _ => 72,
-            },
-            29 => match state {
-                24 => 146,
-                25 => 147,
-                26 => 148,
-                _ => 85,
-            },
-            30 => 128,
-            31 => 158,
-            32 => 138,
-            33 => match state {
-                27 => 149,
-                _ => 86,
-            },
-            34 => 63,
-            35 => 64,
-            36 => match state {
-                11 => 32,
-                30 => 38,
-                55 => 60,
-                8 | 39 => 115,
-                9 => 118,
-                10 => 119,
-                13 => 124,
-                18 => 139,
-                33 | 44 => 156,
-                37 => 167,
-                43 => 173,
-                51 => 189,
-                56 => 198,
-                61 => 205,
-                _ => 87,
-            },
-            38 => match state {
-                28 => 151,
-                29 => 152,
-                _ => 88,
-            },
-            39 => match state {
-                19..=29 => 140,
-                _ => 89,
-            },
-            40 => 65,
-            41 => match state {
-                4 | 8..=11 | 13 | 18..=31 | 33 | 37 | 39 | 43..=44 | 51 | 55..=56 | 61 => 90,
-                _ => 81,
-            },
-            42 => match state {
-                8 | 39 | 44 => 116,
-                _ => 91,
-            },
-            44 => match state {
-                31 => 39,
-                33 => 44,
-                _ => 8,
-            },
-            45 => match state {
-                19 => 141,
-                20 => 142,
-                21 => 143,
-                22 => 144,
-                23 => 145,
-                _ => 92,
-            },
-            46 => match state {
-                32 | 38 => 40,
-                2 | 5 | 46 | 59 => 73,
-                48 => 184,
-                _ => 93,
-            },
-            47 => 170,
-            48 => match state {
-                48 => 185,
-                _ => 94,
-            },
-            49 => match state {
-                32 | 60 => 41,
-                2 | 5 | 46 | 59 => 74,
-                12 => 123,
-                49 => 186,
-                _ => 95,
-            },
-            50 => 171,
-            52 => match state {
-                49 => 187,
-                _ => 96,
-            },
-            53 => match state {
-                1 => 71,
-                _ => 66,
-            },
-            55 => 1,
-            56 => 67,
-            57 => match state {
-                54 => 59,
-                6 => 108,
-                7 => 111,
-                15 => 132,
-                16 => 134,
-                17 => 135,
-                34 => 159,
-                35 => 164,
-                36 => 165,
-                42 => 172,
-                45 => 174,
-                47 => 181,
-                50 => 188,
-                52 => 194,
-                53 => 195,
-                57 => 199,
-                58 => 200,
-                62 => 209,
-                _ => 5,
-            },
-            59 => 15,
-            60 => 97,
-            _ => 0,
-        }
-    }
-    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
-        const __TERMINAL: &[&str] = &[
-            r###""%""###,
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###""->""###,
-            r###"".""###,
-            r###""...""###,
-            r###""/""###,
-            r###"":""###,
-            r###""::""###,
-            r###"";""###,
-            r###""<""###,
-            r###""<=""###,
-            r###""=""###,
-            r###""==""###,
-            r###""=>""###,
-            r###"">""###,
-            r###"">=""###,
-            r###""[""###,
-            r###""]""###,
-            r###""break""###,
-            r###""const""###,
-            r###""dyn""###,
-            r###""else""###,
-            r###""entry""###,
-            r###""extern""###,
-            r###""fn""###,
-            r###""if""###,
-            r###""let""###,
-            r###""loop""###,
-            r###""mut""###,
-            r###""return""###,
-            r###""static""###,
-            r###""use""###,
-            r###""yield""###,
-            r###""{""###,
-            r###""}""###,
-            r###"BHSTRING"###,
-            r###"BSTRING"###,
-            r###"CHSTRING"###,
-            r###"CSTRING"###,
-            r###"FLOAT"###,
-            r###"HSTRING"###,
-            r###"ID"###,
-            r###"INT"###,
-            r###"STRING"###,
-        ];
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            let next_state = __action(__state, index);
-            if next_state == 0 {
-                None
-            } else {
-                Some(alloc::string::ToString::to_string(terminal))
-            }
-        }).collect()
-    }
-    pub(crate) struct __StateMachine<>
-    where 
-    {
-        __phantom: core::marker::PhantomData<()>,
-    }
-    impl<> __state_machine::ParserDefinition for __StateMachine<>
-    where 
-    {
-        type Location = usize;
-        type Error = LexError;
-        type Token = lexer::Token;
-        type TokenIndex = usize;
-        type Symbol = __Symbol<>;
-        type Success = Vec<ast::TopLevelDecl>;
-        type StateIndex = i16;
-        type Action = i16;
-        type ReduceIndex = i16;
-        type NonterminalIndex = usize;
-
-        #[inline]
-        fn start_location(&self) -> Self::Location {
-              Default::default()
-        }
-
-        #[inline]
-        fn start_state(&self) -> Self::StateIndex {
-              0
-        }
-
-        #[inline]
-        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<()>)
-        }
-
-        #[inline]
-        fn action(&self, state: i16, integer: usize) -> i16 {
-            __action(state, integer)
-        }
-
-        #[inline]
-        fn error_action(&self, state: i16) -> i16 {
-            __action(state, 49 - 1)
-        }
-
-        #[inline]
-        fn eof_action(&self, state: i16) -> i16 {
-            __EOF_ACTION[state as usize]
-        }
-
-        #[inline]
-        fn goto(&self, state: i16, nt: usize) -> i16 {
-            __goto(state, nt)
-        }
-
-        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<()>)
-        }
-
-        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens(state)
-        }
-
-        #[inline]
-        fn uses_error_recovery(&self) -> bool {
-            false
-        }
-
-        #[inline]
-        fn error_recovery_symbol(
-            &self,
-            recovery: __state_machine::ErrorRecovery<Self>,
-        ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
-        }
-
-        fn reduce(
-            &mut self,
-            action: i16,
-            start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i16>,
-            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
-        ) -> Option<__state_machine::ParseResult<Self>> {
-            __reduce(
-                action,
-                start_location,
-                states,
-                symbols,
-                core::marker::PhantomData::<()>,
-            )
-        }
-
-        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
-        }
-    }
-    fn __token_to_integer<
-    >(
-        __token: &lexer::Token,
-        _: core::marker::PhantomData<()>,
-    ) -> Option<usize>
-    {
-        match *__token {
-            lexer::Token::Percent if true => Some(0),
-            lexer::Token::OpenPar if true => Some(1),
-            lexer::Token::ClosePar if true => Some(2),
-            lexer::Token::Star if true => Some(3),
-            lexer::Token::Plus if true => Some(4),
-            lexer::Token::Comma if true => Some(5),
-            lexer::Token::Hyphen if true => Some(6),
-            lexer::Token::SingleArrow if true => Some(7),
-            lexer::Token::Dot if true => Some(8),
-            lexer::Token::TplDot if true => Some(9),
-            lexer::Token::Slash if true => Some(10),
-            lexer::Token::Colon if true => Some(11),
-            lexer::Token::DblColon if true => Some(12),
-            lexer::Token::Semicolon if true => Some(13),
-            lexer::Token::Less if true => Some(14),
-            lexer::Token::LessEqual if true => Some(15),
-            lexer::Token::Equals if true => Some(16),
-            lexer::Token::DblEquals if true => Some(17),
-            lexer::Token::DoubleArrow if true => Some(18),
-            lexer::Token::Greater if true => Some(19),
-            lexer::Token::GreaterEqual if true => Some(20),
-            lexer::Token::OpenBracket if true => Some(21),
-            lexer::Token::CloseBracket if true => Some(22),
-            lexer::Token::Break if true => Some(23),
-            lexer::Token::Const if true => Some(24),
-            lexer::Token::Dyn if true => Some(25),
-            lexer::Token::Else if true => Some(26),
-            lexer::Token::Entry if true => Some(27),
-            lexer::Token::Extern if true => Some(28),
-            lexer::Token::Fn if true => Some(29),
-            lexer::Token::If if true => Some(30),
-            lexer::Token::Let if true => Some(31),
-            lexer::Token::Loop if true => Some(32),
-            lexer::Token::Mut if true => Some(33),
-            lexer::Token::Return if true => Some(34),
-            lexer::Token::Static if true => Some(35),
-            lexer::Token::Use if true => Some(36),
-            lexer::Token::Yield if true => Some(37),
-            lexer::Token::OpenBrace if true => Some(38),
-            lexer::Token::CloseBrace if true => Some(39),
-            lexer::Token::ByteHeapString(_) if true => Some(40),
-            lexer::Token::ByteStaticString(_) if true => Some(41),
-            lexer::Token::CHeapString(_) if true => Some(42),
-            lexer::Token::CStaticString(_) if true => Some(43),
-            lexer::Token::Float(_) if true => Some(44),
-            lexer::Token::LokHeapString(_) if true => Some(45),
-            lexer::Token::Identifier(_) if true => Some(46),
-            lexer::Token::Integer(_) if true => Some(47),
-            lexer::Token::LokStaticString(_) if true => Some(48),
-            _ => None,
-        }
-    }
-    fn __token_to_symbol<
-    >(
-        __token_index: usize,
-        __token: lexer::Token,
-        _: core::marker::PhantomData<()>,
-    ) -> __Symbol<>
-    {
-        match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 => __Symbol::Variant0(__token),
-            40 | 41 | 42 | 43 | 45 | 48 => match __token {
-                lexer::Token::ByteHeapString(__tok0) | lexer::Token::ByteStaticString(__tok0) | lexer::Token::CHeapString(__tok0) | lexer::Token::CStaticString(__tok0) | lexer::Token::LokHeapString(__tok0) | lexer::Token::LokStaticString(__tok0) if true => __Symbol::Variant1(__tok0),
-                _ => unreachable!(),
-            },
-            44 | 46 | 47 => match __token {
-                lexer::Token::Float(__tok0) | lexer::Token::Identifier(__tok0) | lexer::Token::Integer(__tok0) if true => __Symbol::Variant2(__tok0),
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
-        }
-    }
-    pub struct LokFileParser {
-        _priv: (),
-    }
-
-    impl LokFileParser {
-        pub fn new() -> LokFileParser {
-            LokFileParser {
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            __TOKEN: __ToTriple<>,
-            __TOKENS: IntoIterator<Item=__TOKEN>,
-        >(
-            &self,
-            __tokens0: __TOKENS,
-        ) -> Result<Vec<ast::TopLevelDecl>, __lalrpop_util::ParseError<usize, lexer::Token, LexError>>
-        {
-            let __tokens = __tokens0.into_iter();
-            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    __phantom: core::marker::PhantomData::<()>,
-                },
-                __tokens,
-            )
-        }
-    }
-    pub(crate) fn __reduce<
-    >(
-        __action: i16,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i16>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> Option<Result<Vec<ast::TopLevelDecl>,__lalrpop_util::ParseError<usize, lexer::Token, LexError>>>
-    {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            1 => {
-                __reduce1(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            2 => {
-                __reduce2(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            3 => {
-                __reduce3(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            4 => {
-                __reduce4(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            5 => {
-                __reduce5(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            6 => {
-                __reduce6(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            7 => {
-                __reduce7(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            8 => {
-                __reduce8(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            9 => {
-                __reduce9(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            10 => {
-                __reduce10(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            11 => {
-                __reduce11(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            12 => {
-                __reduce12(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            13 => {
-                __reduce13(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            14 => {
-                __reduce14(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            15 => {
-                __reduce15(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            16 => {
-                __reduce16(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            17 => {
-                __reduce17(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            18 => {
-                __reduce18(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            19 => {
-                __reduce19(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            20 => {
-                __reduce20(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            21 => {
-                __reduce21(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            22 => {
-                __reduce22(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            23 => {
-                __reduce23(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            24 => {
-                __reduce24(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            25 => {
-                __reduce25(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            26 => {
-                __reduce26(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            27 => {
-                __reduce27(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            28 => {
-                __reduce28(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            29 => {
-                __reduce29(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            30 => {
-                __reduce30(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            31 => {
-                __reduce31(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            32 => {
-                __reduce32(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            33 => {
-                __reduce33(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            34 => {
-                __reduce34(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            35 => {
-                __reduce35(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            36 => {
-                __reduce36(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            37 => {
-                __reduce37(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            38 => {
-                __reduce38(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            39 => {
-                __reduce39(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            40 => {
-                __reduce40(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            41 => {
-                __reduce41(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            42 => {
-                __reduce42(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            43 => {
-                __reduce43(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            44 => {
-                __reduce44(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            45 => {
-                __reduce45(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            46 => {
-                __reduce46(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            47 => {
-                __reduce47(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            48 => {
-                __reduce48(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            49 => {
-                __reduce49(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            50 => {
-                __reduce50(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            51 => {
-                __reduce51(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            52 => {
-                __reduce52(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            53 => {
-                __reduce53(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            54 => {
-                __reduce54(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            55 => {
-                __reduce55(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            56 => {
-                __reduce56(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            57 => {
-                __reduce57(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            58 => {
-                __reduce58(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            59 => {
-                __reduce59(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            60 => {
-                __reduce60(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            61 => {
-                __reduce61(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            62 => {
-                __reduce62(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            63 => {
-                __reduce63(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            64 => {
-                __reduce64(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            65 => {
-                __reduce65(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            66 => {
-                __reduce66(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            67 => {
-                __reduce67(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            68 => {
-                __reduce68(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            69 => {
-                __reduce69(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            70 => {
-                __reduce70(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            71 => {
-                __reduce71(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            72 => {
-                __reduce72(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            73 => {
-                __reduce73(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            74 => {
-                __reduce74(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            75 => {
-                __reduce75(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            76 => {
-                __reduce76(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            77 => {
-                __reduce77(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            78 => {
-                __reduce78(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            79 => {
-                __reduce79(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            80 => {
-                __reduce80(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            81 => {
-                __reduce81(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            82 => {
-                __reduce82(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            83 => {
-                __reduce83(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            84 => {
-                __reduce84(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            85 => {
-                __reduce85(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            86 => {
-                __reduce86(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            87 => {
-                __reduce87(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            88 => {
-                __reduce88(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            89 => {
-                __reduce89(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            90 => {
-                __reduce90(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            91 => {
-                __reduce91(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            92 => {
-                __reduce92(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            93 => {
-                __reduce93(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            94 => {
-                __reduce94(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            95 => {
-                __reduce95(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            96 => {
-                __reduce96(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            97 => {
-                __reduce97(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            98 => {
-                __reduce98(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            99 => {
-                __reduce99(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            100 => {
-                __reduce100(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            101 => {
-                __reduce101(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            102 => {
-                __reduce102(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            103 => {
-                __reduce103(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            104 => {
-                __reduce104(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            105 => {
-                __reduce105(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            106 => {
-                __reduce106(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            107 => {
-                __reduce107(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            108 => {
-                __reduce108(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            109 => {
-                __reduce109(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            110 => {
-                __reduce110(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            111 => {
-                __reduce111(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            112 => {
-                __reduce112(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            113 => {
-                __reduce113(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            114 => {
-                __reduce114(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            115 => {
-                __reduce115(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            116 => {
-                __reduce116(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            117 => {
-                __reduce117(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            118 => {
-                __reduce118(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            119 => {
-                __reduce119(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            120 => {
-                __reduce120(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            121 => {
-                __reduce121(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            122 => {
-                __reduce122(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            123 => {
-                __reduce123(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            124 => {
-                __reduce124(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            125 => {
-                __reduce125(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            126 => {
-                __reduce126(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            127 => {
-                __reduce127(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            128 => {
-                __reduce128(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            129 => {
-                __reduce129(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            130 => {
-                __reduce130(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            131 => {
-                __reduce131(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            132 => {
-                __reduce132(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            133 => {
-                __reduce133(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            134 => {
-                __reduce134(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            135 => {
-                __reduce135(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            136 => {
-                __reduce136(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            137 => {
-                __reduce137(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            138 => {
-                __reduce138(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            139 => {
-                __reduce139(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            140 => {
-                __reduce140(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            141 => {
-                __reduce141(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            142 => {
-                __reduce142(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            143 => {
-                __reduce143(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            144 => {
-                __reduce144(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            145 => {
-                __reduce145(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            146 => {
-                __reduce146(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            147 => {
-                __reduce147(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            148 => {
-                __reduce148(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            149 => {
-                __reduce149(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            150 => {
-                __reduce150(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            151 => {
-                __reduce151(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            152 => {
-                __reduce152(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            153 => {
-                __reduce153(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            154 => {
-                __reduce154(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            155 => {
-                __reduce155(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            156 => {
-                __reduce156(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            157 => {
-                // __LokFile = LokFile => ActionFn(0);
-                let __sym0 = __pop_Variant24(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(__sym0);
-                return Some(Ok(__nt));
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
-    }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
-    }
-    fn __pop_Variant7<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, (String, ast::Type), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant9<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, (core::option::Option<String>, ast::Type), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant28<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Either<Box<ast::If>, Box<ast::Block>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant2<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant18<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<(String, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant19<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<(core::option::Option<String>, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant20<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<ast::Expression>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant24<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<ast::TopLevelDecl>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant1<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<u8>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant11<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<(String, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant12<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant16<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant14<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<ast::Expression>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant27<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<ast::Statement>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant32<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<ast::TopLevelDecl>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant34<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<ast::Type>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant17<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Block, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant21<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Decl, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant22<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Def, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant13<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Expression, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant29<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::If, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant25<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::NSIdent, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant26<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Statement, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant31<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::TopLevelDecl, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant33<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::TopLevelDef, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant5<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ast::Type, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant8<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<(String, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant10<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<(core::option::Option<String>, ast::Type)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant30<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<Either<Box<ast::If>, Box<ast::Block>>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant15<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant4<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<Vec<u8>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant23<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<ast::Expression>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant6<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<ast::Type>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant3<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, core::option::Option<lexer::Token>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant0<
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, lexer::Token, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    pub(crate) fn __reduce0<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "..."? = "..." => ActionFn(87);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action87::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 0)
-    }
-    pub(crate) fn __reduce1<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "..."? =  => ActionFn(88);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action88::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 0)
-    }
-    pub(crate) fn __reduce2<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "extern"? = "extern" => ActionFn(82);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action82::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 1)
-    }
-    pub(crate) fn __reduce3<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "extern"? =  => ActionFn(83);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action83::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 1)
-    }
-    pub(crate) fn __reduce4<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "mut"? = "mut" => ActionFn(71);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 2)
-    }
-    pub(crate) fn __reduce5<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // "mut"? =  => ActionFn(72);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action72::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 2)
-    }
-    pub(crate) fn __reduce6<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("(" <STRING> ")") = "(", STRING, ")" => ActionFn(81);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action81::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 3)
-    }
-    pub(crate) fn __reduce7<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("(" <STRING> ")")? = "(", STRING, ")" => ActionFn(129);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action129::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 4)
-    }
-    pub(crate) fn __reduce8<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("(" <STRING> ")")? =  => ActionFn(80);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action80::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
-    }
-    pub(crate) fn __reduce9<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("->" <Type>) = "->", Type => ActionFn(86);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action86::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 5)
-    }
-    pub(crate) fn __reduce10<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("->" <Type>)? = "->", Type => ActionFn(134);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action134::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 6)
-    }
-    pub(crate) fn __reduce11<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ("->" <Type>)? =  => ActionFn(85);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action85::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 6)
-    }
-    pub(crate) fn __reduce12<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (":" <Type>) = ":", Type => ActionFn(70);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action70::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 7)
-    }
-    pub(crate) fn __reduce13<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (":" <Type>)? = ":", Type => ActionFn(143);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action143::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
-    }
-    pub(crate) fn __reduce14<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (":" <Type>)? =  => ActionFn(69);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action69::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 8)
-    }
-    pub(crate) fn __reduce15<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":") Type) = ID, ":", Type => ActionFn(148);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action148::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (3, 9)
-    }
-    pub(crate) fn __reduce16<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":") Type)? = ID, ":", Type => ActionFn(150);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action150::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 10)
-    }
-    pub(crate) fn __reduce17<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":") Type)? =  => ActionFn(104);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action104::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
-    }
-    pub(crate) fn __reduce18<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":")? Type) = ID, ":", Type => ActionFn(154);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action154::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (3, 11)
-    }
-    pub(crate) fn __reduce19<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":")? Type) = Type => ActionFn(155);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action155::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 11)
-    }
-    pub(crate) fn __reduce20<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":")? Type)? = ID, ":", Type => ActionFn(156);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action156::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 12)
-    }
-    pub(crate) fn __reduce21<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":")? Type)? = Type => ActionFn(157);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action157::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 12)
-    }
-    pub(crate) fn __reduce22<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ((<ID> ":")? Type)? =  => ActionFn(99);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action99::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (0, 12)
-    }
-    pub(crate) fn __reduce23<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":") Type)> ",") = ID, ":", Type, "," => ActionFn(151);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action151::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (4, 13)
-    }
-    pub(crate) fn __reduce24<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":") Type)> ",")* =  => ActionFn(105);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action105::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 14)
-    }
-    pub(crate) fn __reduce25<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":") Type)> ",")* = (<((<ID> ":") Type)> ",")+ => ActionFn(106);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 14)
-    }
-    pub(crate) fn __reduce26<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":") Type)> ",")+ = ID, ":", Type, "," => ActionFn(163);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action163::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (4, 15)
-    }
-    pub(crate) fn __reduce27<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":") Type)> ",")+ = (<((<ID> ":") Type)> ",")+, ID, ":", Type, "," => ActionFn(164);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action164::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (5, 15)
-    }
-    pub(crate) fn __reduce28<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",") = ID, ":", Type, "," => ActionFn(158);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action158::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (4, 16)
-    }
-    pub(crate) fn __reduce29<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",") = Type, "," => ActionFn(159);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action159::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 16)
-    }
-    pub(crate) fn __reduce30<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")* =  => ActionFn(100);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action100::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 17)
-    }
-    pub(crate) fn __reduce31<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")* = (<((<ID> ":")? Type)> ",")+ => ActionFn(101);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action101::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 17)
-    }
-    pub(crate) fn __reduce32<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")+ = ID, ":", Type, "," => ActionFn(169);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action169::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 18)
-    }
-    pub(crate) fn __reduce33<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")+ = Type, "," => ActionFn(170);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action170::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
-    }
-    pub(crate) fn __reduce34<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")+ = (<((<ID> ":")? Type)> ",")+, ID, ":", Type, "," => ActionFn(171);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action171::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 18)
-    }
-    pub(crate) fn __reduce35<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<((<ID> ":")? Type)> ",")+ = (<((<ID> ":")? Type)> ",")+, Type, "," => ActionFn(172);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action172::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 18)
-    }
-    pub(crate) fn __reduce36<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<Expression> ",") = Expression, "," => ActionFn(112);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action112::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 19)
-    }
-    pub(crate) fn __reduce37<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<Expression> ",")* =  => ActionFn(110);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action110::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 20)
-    }
-    pub(crate) fn __reduce38<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<Expression> ",")* = (<Expression> ",")+ => ActionFn(111);
-        let __sym0 = __pop_Variant14(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action111::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 20)
-    }
-    pub(crate) fn __reduce39<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<Expression> ",")+ = Expression, "," => ActionFn(179);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action179::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 21)
-    }
-    pub(crate) fn __reduce40<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<Expression> ",")+ = (<Expression> ",")+, Expression, "," => ActionFn(180);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant14(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action180::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (3, 21)
-    }
-    pub(crate) fn __reduce41<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> ":") = ID, ":" => ActionFn(93);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action93::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 22)
-    }
-    pub(crate) fn __reduce42<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> ":")? = ID, ":" => ActionFn(149);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action149::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 23)
-    }
-    pub(crate) fn __reduce43<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> ":")? =  => ActionFn(92);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action92::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 23)
-    }
-    pub(crate) fn __reduce44<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> "::") = ID, "::" => ActionFn(62);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action62::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 24)
-    }
-    pub(crate) fn __reduce45<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> "::")* =  => ActionFn(60);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action60::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 25)
-    }
-    pub(crate) fn __reduce46<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> "::")* = (<ID> "::")+ => ActionFn(61);
-        let __sym0 = __pop_Variant16(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action61::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 25)
-    }
-    pub(crate) fn __reduce47<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> "::")+ = ID, "::" => ActionFn(183);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action183::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (2, 26)
-    }
-    pub(crate) fn __reduce48<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // (<ID> "::")+ = (<ID> "::")+, ID, "::" => ActionFn(184);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant16(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action184::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (3, 26)
-    }
-    pub(crate) fn __reduce49<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // AssignExpression = LValueExpression, "=", CompareExpression => ActionFn(26);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action26::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 27)
-    }
-    pub(crate) fn __reduce50<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // AssignExpression = CompareExpression => ActionFn(27);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 27)
-    }
-    pub(crate) fn __reduce51<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Block = TaillessBlock => ActionFn(9);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 28)
-    }
-    pub(crate) fn __reduce52<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Block = TailedBlock => ActionFn(10);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 28)
-    }
-    pub(crate) fn __reduce53<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CallExpression = CallExpression, "(", Comma<Expression>, ")" => ActionFn(41);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action41::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (4, 29)
-    }
-    pub(crate) fn __reduce54<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CallExpression = ValueExpression => ActionFn(42);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 29)
-    }
-    pub(crate) fn __reduce55<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":") Type)> = ID, ":", Type => ActionFn(165);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action165::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (3, 30)
-    }
-    pub(crate) fn __reduce56<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":") Type)> = (<((<ID> ":") Type)> ",")+, ID, ":", Type => ActionFn(166);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action166::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (4, 30)
-    }
-    pub(crate) fn __reduce57<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":") Type)> =  => ActionFn(167);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action167::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 30)
-    }
-    pub(crate) fn __reduce58<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":") Type)> = (<((<ID> ":") Type)> ",")+ => ActionFn(168);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action168::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 30)
-    }
-    pub(crate) fn __reduce59<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> = ID, ":", Type => ActionFn(173);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action173::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 31)
-    }
-    pub(crate) fn __reduce60<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> = (<((<ID> ":")? Type)> ",")+, ID, ":", Type => ActionFn(174);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action174::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (4, 31)
-    }
-    pub(crate) fn __reduce61<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> = Type => ActionFn(175);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action175::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 31)
-    }
-    pub(crate) fn __reduce62<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> = (<((<ID> ":")? Type)> ",")+, Type => ActionFn(176);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action176::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 31)
-    }
-    pub(crate) fn __reduce63<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> =  => ActionFn(177);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action177::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 31)
-    }
-    pub(crate) fn __reduce64<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<((<ID> ":")? Type)> = (<((<ID> ":")? Type)> ",")+ => ActionFn(178);
-        let __sym0 = __pop_Variant12(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action178::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 31)
-    }
-    pub(crate) fn __reduce65<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<Expression> = Expression => ActionFn(187);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action187::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 32)
-    }
-    pub(crate) fn __reduce66<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<Expression> =  => ActionFn(188);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action188::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (0, 32)
-    }
-    pub(crate) fn __reduce67<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<Expression> = (<Expression> ",")+, Expression => ActionFn(189);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant14(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action189::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (2, 32)
-    }
-    pub(crate) fn __reduce68<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Comma<Expression> = (<Expression> ",")+ => ActionFn(190);
-        let __sym0 = __pop_Variant14(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action190::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 32)
-    }
-    pub(crate) fn __reduce69<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = CompareExpression, "<", SumExpression => ActionFn(28);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action28::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 33)
-    }
-    pub(crate) fn __reduce70<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = CompareExpression, "<=", SumExpression => ActionFn(29);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action29::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 33)
-    }
-    pub(crate) fn __reduce71<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = CompareExpression, "==", SumExpression => ActionFn(30);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action30::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 33)
-    }
-    pub(crate) fn __reduce72<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = CompareExpression, ">=", SumExpression => ActionFn(31);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action31::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 33)
-    }
-    pub(crate) fn __reduce73<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = CompareExpression, ">", SumExpression => ActionFn(32);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action32::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 33)
-    }
-    pub(crate) fn __reduce74<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // CompareExpression = SumExpression => ActionFn(33);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 33)
-    }
-    pub(crate) fn __reduce75<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Decl = "extern", "use", "(", STRING, ")", STRING => ActionFn(130);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action130::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (6, 34)
-    }
-    pub(crate) fn __reduce76<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Decl = "extern", "use", STRING => ActionFn(131);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action131::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (3, 34)
-    }
-    pub(crate) fn __reduce77<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Decl = "use", "(", STRING, ")", STRING => ActionFn(132);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action132::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (5, 34)
-    }
-    pub(crate) fn __reduce78<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Decl = "use", STRING => ActionFn(133);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action133::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (2, 34)
-    }
-    pub(crate) fn __reduce79<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Def = "fn", ID, "(", Comma<((<ID> ":") Type)>, ")", "->", Type, Block => ActionFn(135);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant17(__symbols);
-        let __sym6 = __pop_Variant5(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant18(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action135::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (8, 35)
-    }
-    pub(crate) fn __reduce80<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Def = "fn", ID, "(", Comma<((<ID> ":") Type)>, ")", Block => ActionFn(136);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant17(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant18(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action136::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (6, 35)
-    }
-    pub(crate) fn __reduce81<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Expression = AssignExpression => ActionFn(25);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 36)
-    }
-    pub(crate) fn __reduce82<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Expression? = Expression => ActionFn(66);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action66::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 37)
-    }
-    pub(crate) fn __reduce83<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Expression? =  => ActionFn(67);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action67::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (0, 37)
-    }
-    pub(crate) fn __reduce84<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // FactorExpression = FactorExpression, "*", CallExpression => ActionFn(37);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action37::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 38)
-    }
-    pub(crate) fn __reduce85<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // FactorExpression = FactorExpression, "/", CallExpression => ActionFn(38);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action38::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 38)
-    }
-    pub(crate) fn __reduce86<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // FactorExpression = FactorExpression, "%", CallExpression => ActionFn(39);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action39::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 38)
-    }
-    pub(crate) fn __reduce87<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // FactorExpression = CallExpression => ActionFn(40);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
-    }
-    pub(crate) fn __reduce88<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // LValueExpression = TailedBlock => ActionFn(48);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 39)
-    }
-    pub(crate) fn __reduce89<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // LValueExpression = TailedIf => ActionFn(49);
-        let __sym0 = __pop_Variant29(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 39)
-    }
-    pub(crate) fn __reduce90<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // LValueExpression = NSIdent => ActionFn(50);
-        let __sym0 = __pop_Variant25(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 39)
-    }
-    pub(crate) fn __reduce91<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // LokFile =  => ActionFn(201);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action201::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (0, 40)
-    }
-    pub(crate) fn __reduce92<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // LokFile = TopLevelDecl+ => ActionFn(202);
-        let __sym0 = __pop_Variant32(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action202::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 40)
-    }
-    pub(crate) fn __reduce93<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // NSIdent = ID => ActionFn(185);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action185::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 41)
-    }
-    pub(crate) fn __reduce94<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // NSIdent = (<ID> "::")+, ID => ActionFn(186);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant16(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action186::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (2, 41)
-    }
-    pub(crate) fn __reduce95<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = TaillessBlock => ActionFn(19);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 42)
-    }
-    pub(crate) fn __reduce96<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = TaillessIf => ActionFn(20);
-        let __sym0 = __pop_Variant29(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 42)
-    }
-    pub(crate) fn __reduce97<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = Expression, ";" => ActionFn(21);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action21::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (2, 42)
-    }
-    pub(crate) fn __reduce98<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "let", "mut", ID, ":", Type, "=", Expression, ";" => ActionFn(144);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant13(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant5(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action144::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (8, 42)
-    }
-    pub(crate) fn __reduce99<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "let", "mut", ID, "=", Expression, ";" => ActionFn(145);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant13(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action145::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (6, 42)
-    }
-    pub(crate) fn __reduce100<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "let", ID, ":", Type, "=", Expression, ";" => ActionFn(146);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant13(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action146::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (7, 42)
-    }
-    pub(crate) fn __reduce101<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "let", ID, "=", Expression, ";" => ActionFn(147);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action147::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (5, 42)
-    }
-    pub(crate) fn __reduce102<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "break", Expression, ";" => ActionFn(191);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action191::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (3, 42)
-    }
-    pub(crate) fn __reduce103<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "break", ";" => ActionFn(192);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action192::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (2, 42)
-    }
-    pub(crate) fn __reduce104<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "return", Expression, ";" => ActionFn(193);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action193::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (3, 42)
-    }
-    pub(crate) fn __reduce105<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement = "return", ";" => ActionFn(194);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action194::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (2, 42)
-    }
-    pub(crate) fn __reduce106<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement* =  => ActionFn(75);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action75::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (0, 43)
-    }
-    pub(crate) fn __reduce107<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement* = Statement+ => ActionFn(76);
-        let __sym0 = __pop_Variant27(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action76::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 43)
-    }
-    pub(crate) fn __reduce108<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement+ = Statement => ActionFn(108);
-        let __sym0 = __pop_Variant26(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action108::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 44)
-    }
-    pub(crate) fn __reduce109<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Statement+ = Statement+, Statement => ActionFn(109);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant26(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action109::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (2, 44)
-    }
-    pub(crate) fn __reduce110<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // SumExpression = SumExpression, "+", FactorExpression => ActionFn(34);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action34::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 45)
-    }
-    pub(crate) fn __reduce111<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // SumExpression = SumExpression, "-", FactorExpression => ActionFn(35);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action35::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 45)
-    }
-    pub(crate) fn __reduce112<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // SumExpression = FactorExpression => ActionFn(36);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 45)
-    }
-    pub(crate) fn __reduce113<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TailedBlock = "{", Expression, "}" => ActionFn(195);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action195::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (3, 46)
-    }
-    pub(crate) fn __reduce114<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TailedBlock = "{", Statement+, Expression, "}" => ActionFn(196);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant27(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action196::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (4, 46)
-    }
-    pub(crate) fn __reduce115<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TailedElse = "else", TailedIf => ActionFn(14);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action14::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 47)
-    }
-    pub(crate) fn __reduce116<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TailedElse = "else", TailedBlock => ActionFn(15);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action15::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 47)
-    }
-    pub(crate) fn __reduce117<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TailedIf = "if", Expression, TailedBlock, TailedElse => ActionFn(13);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant28(__symbols);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action13::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (4, 48)
-    }
-    pub(crate) fn __reduce118<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessBlock = "{", "}" => ActionFn(197);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action197::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (2, 49)
-    }
-    pub(crate) fn __reduce119<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessBlock = "{", Statement+, "}" => ActionFn(198);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant27(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action198::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (3, 49)
-    }
-    pub(crate) fn __reduce120<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessElse = "else", TaillessIf => ActionFn(17);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action17::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 50)
-    }
-    pub(crate) fn __reduce121<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessElse = "else", TaillessBlock => ActionFn(18);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action18::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 50)
-    }
-    pub(crate) fn __reduce122<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessElse? = TaillessElse => ActionFn(73);
-        let __sym0 = __pop_Variant28(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (1, 51)
-    }
-    pub(crate) fn __reduce123<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessElse? =  => ActionFn(74);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action74::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (0, 51)
-    }
-    pub(crate) fn __reduce124<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessIf = "if", Expression, TaillessBlock, TaillessElse => ActionFn(199);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant28(__symbols);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action199::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (4, 52)
-    }
-    pub(crate) fn __reduce125<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TaillessIf = "if", Expression, TaillessBlock => ActionFn(200);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action200::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (3, 52)
-    }
-    pub(crate) fn __reduce126<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = "extern", "fn", ID, "(", Comma<((<ID> ":")? Type)>, "...", ")", "->", Type, ";" => ActionFn(137);
-        assert!(__symbols.len() >= 10);
-        let __sym9 = __pop_Variant0(__symbols);
-        let __sym8 = __pop_Variant5(__symbols);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant19(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym9.2.clone();
-        let __nt = super::__action137::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (10, 53)
-    }
-    pub(crate) fn __reduce127<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = "extern", "fn", ID, "(", Comma<((<ID> ":")? Type)>, "...", ")", ";" => ActionFn(138);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant19(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action138::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (8, 53)
-    }
-    pub(crate) fn __reduce128<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = "extern", "fn", ID, "(", Comma<((<ID> ":")? Type)>, ")", "->", Type, ";" => ActionFn(139);
-        assert!(__symbols.len() >= 9);
-        let __sym8 = __pop_Variant0(__symbols);
-        let __sym7 = __pop_Variant5(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant19(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym8.2.clone();
-        let __nt = super::__action139::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (9, 53)
-    }
-    pub(crate) fn __reduce129<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = "extern", "fn", ID, "(", Comma<((<ID> ":")? Type)>, ")", ";" => ActionFn(140);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant19(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action140::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (7, 53)
-    }
-    pub(crate) fn __reduce130<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = TopLevelDef => ActionFn(3);
-        let __sym0 = __pop_Variant33(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (1, 53)
-    }
-    pub(crate) fn __reduce131<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl = Decl => ActionFn(4);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (1, 53)
-    }
-    pub(crate) fn __reduce132<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl* =  => ActionFn(94);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action94::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (0, 54)
-    }
-    pub(crate) fn __reduce133<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl* = TopLevelDecl+ => ActionFn(95);
-        let __sym0 = __pop_Variant32(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action95::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 54)
-    }
-    pub(crate) fn __reduce134<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl+ = TopLevelDecl => ActionFn(96);
-        let __sym0 = __pop_Variant31(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action96::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 55)
-    }
-    pub(crate) fn __reduce135<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDecl+ = TopLevelDecl+, TopLevelDecl => ActionFn(97);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant31(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action97::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (2, 55)
-    }
-    pub(crate) fn __reduce136<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDef = "entry", "->", Type, Block => ActionFn(141);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant17(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action141::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (4, 56)
-    }
-    pub(crate) fn __reduce137<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDef = "entry", Block => ActionFn(142);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action142::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (2, 56)
-    }
-    pub(crate) fn __reduce138<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // TopLevelDef = Def => ActionFn(7);
-        let __sym0 = __pop_Variant22(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 56)
-    }
-    pub(crate) fn __reduce139<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = NSIdent => ActionFn(51);
-        let __sym0 = __pop_Variant25(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 57)
-    }
-    pub(crate) fn __reduce140<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "*", "const", Type => ActionFn(52);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action52::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 57)
-    }
-    pub(crate) fn __reduce141<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "*", "mut", Type => ActionFn(53);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action53::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 57)
-    }
-    pub(crate) fn __reduce142<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "*", "dyn", "const", Type => ActionFn(54);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action54::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (4, 57)
-    }
-    pub(crate) fn __reduce143<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "*", "dyn", "mut", Type => ActionFn(55);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant5(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action55::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (4, 57)
-    }
-    pub(crate) fn __reduce144<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "[", Type, "]" => ActionFn(56);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action56::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 57)
-    }
-    pub(crate) fn __reduce145<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "[", Type, ";", INT, "]" => ActionFn(57);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action57::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (5, 57)
-    }
-    pub(crate) fn __reduce146<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "(", ")" => ActionFn(203);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action203::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 57)
-    }
-    pub(crate) fn __reduce147<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type = "(", Type+, ")" => ActionFn(204);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant34(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action204::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 57)
-    }
-    pub(crate) fn __reduce148<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type* =  => ActionFn(63);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action63::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (0, 58)
-    }
-    pub(crate) fn __reduce149<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type* = Type+ => ActionFn(64);
-        let __sym0 = __pop_Variant34(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 58)
-    }
-    pub(crate) fn __reduce150<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type+ = Type => ActionFn(113);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action113::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 59)
-    }
-    pub(crate) fn __reduce151<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Type+ = Type+, Type => ActionFn(114);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant34(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action114::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (2, 59)
-    }
-    pub(crate) fn __reduce152<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ValueExpression = INT => ActionFn(43);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 60)
-    }
-    pub(crate) fn __reduce153<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ValueExpression = LValueExpression => ActionFn(44);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 60)
-    }
-    pub(crate) fn __reduce154<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ValueExpression = CSTRING => ActionFn(45);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 60)
-    }
-    pub(crate) fn __reduce155<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ValueExpression = "loop", TaillessBlock => ActionFn(46);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action46::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 60)
-    }
-    pub(crate) fn __reduce156<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ValueExpression = "(", Expression, ")" => ActionFn(47);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action47::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 60)
-    }
-}
-pub use self::__parse__LokFile::LokFileParser;
-
-fn __action0<
->(
-    (_, __0, _): (usize, Vec<ast::TopLevelDecl>, usize),
-) -> Vec<ast::TopLevelDecl>
-{
-    __0
-}
-
-fn __action1<
->(
-    (_, __0, _): (usize, alloc::vec::Vec<ast::TopLevelDecl>, usize),
-) -> Vec<ast::TopLevelDecl>
-{
-    __0
-}
-
-fn __action2<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, params, _): (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    (_, varadic, _): (usize, core::option::Option<lexer::Token>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, returns, _): (usize, core::option::Option<ast::Type>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    {
-		ast::TopLevelDecl::FnExtern(ast::FnExtern {
-			name,
-			params,
-			varadic: varadic.is_some(),
-			returns,
-		})
-	}
-}
-
-fn __action3<
->(
-    (_, __0, _): (usize, ast::TopLevelDef, usize),
-) -> ast::TopLevelDecl
-{
-    ast::TopLevelDecl::Def(__0)
-}
-
-fn __action4<
->(
-    (_, __0, _): (usize, ast::Decl, usize),
-) -> ast::TopLevelDecl
-{
-    ast::TopLevelDecl::Decl(__0)
-}
-
-fn __action5<
->(
-    (_, external, _): (usize, core::option::Option<lexer::Token>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, ty, _): (usize, core::option::Option<Vec<u8>>, usize),
-    (_, path, _): (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    {
-		ast::Decl::Use(ast::Use {
-			external: external.is_some(),
-			ty,
-			path,
-		})
-	}
-}
-
-fn __action6<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, returns, _): (usize, core::option::Option<ast::Type>, usize),
-    (_, body, _): (usize, ast::Block, usize),
-) -> ast::TopLevelDef
-{
-    {
-		ast::TopLevelDef::Entry(ast::Entry {
-			returns,
-			body
-		})
-	}
-}
-
-fn __action7<
->(
-    (_, __0, _): (usize, ast::Def, usize),
-) -> ast::TopLevelDef
-{
-    ast::TopLevelDef::Def(__0)
-}
-
-fn __action8<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, params, _): (usize, Vec<(String, ast::Type)>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, returns, _): (usize, core::option::Option<ast::Type>, usize),
-    (_, body, _): (usize, ast::Block, usize),
-) -> ast::Def
-{
-    {
-		ast::Def::Fn(ast::FnDef {
-			name,
-			params,
-			returns,
-			body,
-		})
-	}
-}
-
-fn __action9<
->(
-    (_, __0, _): (usize, ast::Block, usize),
-) -> ast::Block
-{
-    __0
-}
-
-fn __action10<
->(
-    (_, __0, _): (usize, ast::Block, usize),
-) -> ast::Block
-{
-    __0
-}
-
-fn __action11<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, statements, _): (usize, alloc::vec::Vec<ast::Statement>, usize),
-    (_, tail, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    {
-		ast::Block {
-			statements,
-			tail: Some(tail),
-		}
-	}
-}
-
-fn __action12<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, statements, _): (usize, alloc::vec::Vec<ast::Statement>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    {
-		ast::Block {
-			statements,
-			tail: None,
-		}
-	}
-}
-
-fn __action13<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, cond, _): (usize, ast::Expression, usize),
-    (_, if_true, _): (usize, ast::Block, usize),
-    (_, if_false, _): (usize, Either<Box<ast::If>, Box<ast::Block>>, usize),
-) -> ast::If
-{
-    ast::If(Box::new(cond), Box::new(if_true), Some(if_false))
-}
-
-fn __action14<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::If, usize),
-) -> Either<Box<ast::If>, Box<ast::Block>>
-{
-    Left(Box::new(__0))
-}
-
-fn __action15<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Block, usize),
-) -> Either<Box<ast::If>, Box<ast::Block>>
-{
-    Right(Box::new(__0))
-}
-
-fn __action16<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, cond, _): (usize, ast::Expression, usize),
-    (_, if_true, _): (usize, ast::Block, usize),
-    (_, if_false, _): (usize, core::option::Option<Either<Box<ast::If>, Box<ast::Block>>>, usize),
-) -> ast::If
-{
-    ast::If(Box::new(cond), Box::new(if_true), if_false)
-}
-
-fn __action17<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::If, usize),
-) -> Either<Box<ast::If>, Box<ast::Block>>
-{
-    Left(Box::new(__0))
-}
-
-fn __action18<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Block, usize),
-) -> Either<Box<ast::If>, Box<ast::Block>>
-{
-    Right(Box::new(__0))
-}
-
-fn __action19<
->(
-    (_, __0, _): (usize, ast::Block, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Expression(ast::Expression::Block(Box::new(__0)))
-	}
-}
-
-fn __action20<
->(
-    (_, __0, _): (usize, ast::If, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Expression(ast::Expression::If(__0))
-	}
-}
-
-fn __action21<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Expression(__0)
-	}
-}
-
-fn __action22<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, m, _): (usize, core::option::Option<lexer::Token>, usize),
-    (_, n, _): (usize, String, usize),
-    (_, t, _): (usize, core::option::Option<ast::Type>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, e, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Decl {
-			name: n,
-			mutable: m.is_some(),
-			expected_type: t,
-			value: e,
-		}
-	}
-}
-
-fn __action23<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, core::option::Option<ast::Expression>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Break(__0)
-	}
-}
-
-fn __action24<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, core::option::Option<ast::Expression>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    {
-		ast::Statement::Return(__0)
-	}
-}
-
-fn __action25<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action26<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Assign(Box::new(lhs), None, Box::new(rhs))
-}
-
-fn __action27<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action28<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Lt, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action29<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Le, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action30<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Eq, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action31<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Ge, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action32<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Gt, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action33<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action34<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Add, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action35<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Sub, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action36<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action37<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Mul, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action38<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Div, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action39<
->(
-    (_, lhs, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, rhs, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    ast::Expression::Op(ast::Op::Rem, Box::new(lhs), Box::new(rhs))
-}
-
-fn __action40<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action41<
->(
-    (_, e, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, p, _): (usize, Vec<ast::Expression>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Expression
-{
-    ast::Expression::Call(Box::new(e), p)
-}
-
-fn __action42<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action43<
->(
-    (_, __0, _): (usize, String, usize),
-) -> ast::Expression
-{
-    ast::Expression::Int(str::parse(&__0).unwrap())
-}
-
-fn __action44<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action45<
->(
-    (_, __0, _): (usize, Vec<u8>, usize),
-) -> ast::Expression
-{
-    ast::Expression::CStringRef(__0)
-}
-
-fn __action46<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Block, usize),
-) -> ast::Expression
-{
-    ast::Expression::Loop(Box::new(__0))
-}
-
-fn __action47<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action48<
->(
-    (_, __0, _): (usize, ast::Block, usize),
-) -> ast::Expression
-{
-    ast::Expression::Block(Box::new(__0))
-}
-
-fn __action49<
->(
-    (_, __0, _): (usize, ast::If, usize),
-) -> ast::Expression
-{
-    ast::Expression::If(__0)
-}
-
-fn __action50<
->(
-    (_, __0, _): (usize, ast::NSIdent, usize),
-) -> ast::Expression
-{
-    ast::Expression::LVar(__0)
-}
-
-fn __action51<
->(
-    (_, __0, _): (usize, ast::NSIdent, usize),
-) -> ast::Type
-{
-    ast::Type::Name(__0)
-}
-
-fn __action52<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    ast::Type::PtrConst(Box::new(__0))
-}
-
-fn __action53<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    ast::Type::PtrMut(Box::new(__0))
-}
-
-fn __action54<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    ast::Type::PtrDynConst(Box::new(__0))
-}
-
-fn __action55<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    ast::Type::PtrDynMut(Box::new(__0))
-}
-
-fn __action56<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Type
-{
-    ast::Type::Slice(Box::new(__0))
-}
-
-fn __action57<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, t, _): (usize, ast::Type, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, n, _): (usize, String, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Type
-{
-    ast::Type::Arr(Box::new(t), str::parse(&n).unwrap())
-}
-
-fn __action58<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, alloc::vec::Vec<ast::Type>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Type
-{
-    ast::Type::Tuple(__0)
-}
-
-fn __action59<
->(
-    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> ast::NSIdent
-{
-    {
-		v.push(e);
-		v
-	}
-}
-
-fn __action60<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
-{
-    alloc::vec![]
-}
-
-fn __action61<
->(
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
-{
-    v
-}
-
-fn __action62<
->(
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> String
-{
-    __0
-}
-
-fn __action63<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ast::Type>
-{
-    alloc::vec![]
-}
-
-fn __action64<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Type>, usize),
-) -> alloc::vec::Vec<ast::Type>
-{
-    v
-}
-
-fn __action65<
->(
-    (_, mut v, _): (usize, alloc::vec::Vec<ast::Expression>, usize),
-    (_, e, _): (usize, core::option::Option<ast::Expression>, usize),
-) -> Vec<ast::Expression>
-{
-    match e {
-        None => v,
-        Some(e) => {
-            v.push(e);
-            v
-        }
-    }
-}
-
-fn __action66<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> core::option::Option<ast::Expression>
-{
-    Some(__0)
-}
-
-fn __action67<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<ast::Expression>
-{
-    None
-}
-
-fn __action68<
->(
-    (_, __0, _): (usize, ast::Type, usize),
-) -> core::option::Option<ast::Type>
-{
-    Some(__0)
-}
-
-fn __action69<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<ast::Type>
-{
-    None
-}
-
-fn __action70<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    __0
-}
-
-fn __action71<
->(
-    (_, __0, _): (usize, lexer::Token, usize),
-) -> core::option::Option<lexer::Token>
-{
-    Some(__0)
-}
-
-fn __action72<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<lexer::Token>
-{
-    None
-}
-
-fn __action73<
->(
-    (_, __0, _): (usize, Either<Box<ast::If>, Box<ast::Block>>, usize),
-) -> core::option::Option<Either<Box<ast::If>, Box<ast::Block>>>
-{
-    Some(__0)
-}
-
-fn __action74<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Either<Box<ast::If>, Box<ast::Block>>>
-{
-    None
-}
-
-fn __action75<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ast::Statement>
-{
-    alloc::vec![]
-}
-
-fn __action76<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Statement>, usize),
-) -> alloc::vec::Vec<ast::Statement>
-{
-    v
-}
-
-fn __action77<
->(
-    (_, mut v, _): (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-    (_, e, _): (usize, core::option::Option<(String, ast::Type)>, usize),
-) -> Vec<(String, ast::Type)>
-{
-    match e {
-        None => v,
-        Some(e) => {
-            v.push(e);
-            v
-        }
-    }
-}
-
-fn __action78<
->(
-    (_, __0, _): (usize, String, usize),
-    (_, __1, _): (usize, ast::Type, usize),
-) -> (String, ast::Type)
-{
-    (__0, __1)
-}
-
-fn __action79<
->(
-    (_, __0, _): (usize, Vec<u8>, usize),
-) -> core::option::Option<Vec<u8>>
-{
-    Some(__0)
-}
-
-fn __action80<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Vec<u8>>
-{
-    None
-}
-
-fn __action81<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, Vec<u8>, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> Vec<u8>
-{
-    __0
-}
-
-fn __action82<
->(
-    (_, __0, _): (usize, lexer::Token, usize),
-) -> core::option::Option<lexer::Token>
-{
-    Some(__0)
-}
-
-fn __action83<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<lexer::Token>
-{
-    None
-}
-
-fn __action84<
->(
-    (_, __0, _): (usize, ast::Type, usize),
-) -> core::option::Option<ast::Type>
-{
-    Some(__0)
-}
-
-fn __action85<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<ast::Type>
-{
-    None
-}
-
-fn __action86<
->(
-    (_, _, _): (usize, lexer::Token, usize),
-    (_, __0, _): (usize, ast::Type, usize),
-) -> ast::Type
-{
-    __0
-}
-
-fn __action87<
->(
-    (_, __0, _): (usize, lexer::Token, usize),
-) -> core::option::Option<lexer::Token>
-{
-    Some(__0)
-}
-
-fn __action88<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<lexer::Token>
-{
-    None
-}
-
-fn __action89<
->(
-    (_, mut v, _): (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    (_, e, _): (usize, core::option::Option<(core::option::Option<String>, ast::Type)>, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    match e {
-        None => v,
-        Some(e) => {
-            v.push(e);
-            v
-        }
-    }
-}
-
-fn __action90<
->(
-    (_, __0, _): (usize, core::option::Option<String>, usize),
-    (_, __1, _): (usize, ast::Type, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    (__0, __1)
-}
-
-fn __action91<
->(
-    (_, __0, _): (usize, String, usize),
-) -> core::option::Option<String>
-{
-    Some(__0)
-}
-
-fn __action92<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<String>
-{
-    None
-}
-
-fn __action93<
->(
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> String
-{
-    __0
-}
-
-fn __action94<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ast::TopLevelDecl>
-{
-    alloc::vec![]
-}
-
-fn __action95<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::TopLevelDecl>, usize),
-) -> alloc::vec::Vec<ast::TopLevelDecl>
-{
-    v
-}
-
-fn __action96<
->(
-    (_, __0, _): (usize, ast::TopLevelDecl, usize),
-) -> alloc::vec::Vec<ast::TopLevelDecl>
-{
-    alloc::vec![__0]
-}
-
-fn __action97<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::TopLevelDecl>, usize),
-    (_, e, _): (usize, ast::TopLevelDecl, usize),
-) -> alloc::vec::Vec<ast::TopLevelDecl>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action98<
->(
-    (_, __0, _): (usize, (core::option::Option<String>, ast::Type), usize),
-) -> core::option::Option<(core::option::Option<String>, ast::Type)>
-{
-    Some(__0)
-}
-
-fn __action99<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<(core::option::Option<String>, ast::Type)>
-{
-    None
-}
-
-fn __action100<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    alloc::vec![]
-}
-
-fn __action101<
->(
-    (_, v, _): (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    v
-}
-
-fn __action102<
->(
-    (_, __0, _): (usize, (core::option::Option<String>, ast::Type), usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    __0
-}
-
-fn __action103<
->(
-    (_, __0, _): (usize, (String, ast::Type), usize),
-) -> core::option::Option<(String, ast::Type)>
-{
-    Some(__0)
-}
-
-fn __action104<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<(String, ast::Type)>
-{
-    None
-}
-
-fn __action105<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    alloc::vec![]
-}
-
-fn __action106<
->(
-    (_, v, _): (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    v
-}
-
-fn __action107<
->(
-    (_, __0, _): (usize, (String, ast::Type), usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> (String, ast::Type)
-{
-    __0
-}
-
-fn __action108<
->(
-    (_, __0, _): (usize, ast::Statement, usize),
-) -> alloc::vec::Vec<ast::Statement>
-{
-    alloc::vec![__0]
-}
-
-fn __action109<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Statement>, usize),
-    (_, e, _): (usize, ast::Statement, usize),
-) -> alloc::vec::Vec<ast::Statement>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action110<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ast::Expression>
-{
-    alloc::vec![]
-}
-
-fn __action111<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Expression>, usize),
-) -> alloc::vec::Vec<ast::Expression>
-{
-    v
-}
-
-fn __action112<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-    (_, _, _): (usize, lexer::Token, usize),
-) -> ast::Expression
-{
-    __0
-}
-
-fn __action113<
->(
-    (_, __0, _): (usize, ast::Type, usize),
-) -> alloc::vec::Vec<ast::Type>
-{
-    alloc::vec![__0]
-}
-
-fn __action114<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Type>, usize),
-    (_, e, _): (usize, ast::Type, usize),
-) -> alloc::vec::Vec<ast::Type>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action115<
->(
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
-{
-    alloc::vec![__0]
-}
-
-fn __action116<
->(
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action117<
->(
-    (_, __0, _): (usize, ast::Expression, usize),
-) -> alloc::vec::Vec<ast::Expression>
-{
-    alloc::vec![__0]
-}
-
-fn __action118<
->(
-    (_, v, _): (usize, alloc::vec::Vec<ast::Expression>, usize),
-    (_, e, _): (usize, ast::Expression, usize),
-) -> alloc::vec::Vec<ast::Expression>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action119<
->(
-    (_, __0, _): (usize, (String, ast::Type), usize),
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    alloc::vec![__0]
-}
-
-fn __action120<
->(
-    (_, v, _): (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-    (_, e, _): (usize, (String, ast::Type), usize),
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action121<
->(
-    (_, __0, _): (usize, (core::option::Option<String>, ast::Type), usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    alloc::vec![__0]
-}
-
-fn __action122<
->(
-    (_, v, _): (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    (_, e, _): (usize, (core::option::Option<String>, ast::Type), usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    { let mut v = v; v.push(e); v }
-}
-
-fn __action123<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, lexer::Token, usize),
-    __7: (usize, core::option::Option<ast::Type>, usize),
-    __8: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __5.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action87(
-        __5,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action2(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __temp0,
-        __6,
-        __7,
-        __8,
-    )
-}
-
-fn __action124<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, core::option::Option<ast::Type>, usize),
-    __7: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __4.2.clone();
-    let __end0 = __5.0.clone();
-    let __temp0 = __action88(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action2(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __temp0,
-        __5,
-        __6,
-        __7,
-    )
-}
-
-fn __action125<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, core::option::Option<Vec<u8>>, usize),
-    __3: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action82(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action5(
-        __temp0,
-        __1,
-        __2,
-        __3,
-    )
-}
-
-fn __action126<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, core::option::Option<Vec<u8>>, usize),
-    __2: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action83(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action5(
-        __temp0,
-        __0,
-        __1,
-        __2,
-    )
-}
-
-fn __action127<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, core::option::Option<ast::Type>, usize),
-    __4: (usize, lexer::Token, usize),
-    __5: (usize, ast::Expression, usize),
-    __6: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action71(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action22(
-        __0,
-        __temp0,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-    )
-}
-
-fn __action128<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, String, usize),
-    __2: (usize, core::option::Option<ast::Type>, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, ast::Expression, usize),
-    __5: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action72(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action22(
-        __0,
-        __temp0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-    )
-}
-
-fn __action129<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, Vec<u8>, usize),
-    __2: (usize, lexer::Token, usize),
-) -> core::option::Option<Vec<u8>>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action81(
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action79(
-        __temp0,
-    )
-}
-
-fn __action130<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, Vec<u8>, usize),
-    __4: (usize, lexer::Token, usize),
-    __5: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action129(
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action125(
-        __0,
-        __1,
-        __temp0,
-        __5,
-    )
-}
-
-fn __action131<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action80(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action125(
-        __0,
-        __1,
-        __temp0,
-        __2,
-    )
-}
-
-fn __action132<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, Vec<u8>, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action129(
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action126(
-        __0,
-        __temp0,
-        __4,
-    )
-}
-
-fn __action133<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, Vec<u8>, usize),
-) -> ast::Decl
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action80(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action126(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action134<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Type, usize),
-) -> core::option::Option<ast::Type>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action86(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        __temp0,
-    )
-}
-
-fn __action135<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, Vec<(String, ast::Type)>, usize),
-    __4: (usize, lexer::Token, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, ast::Type, usize),
-    __7: (usize, ast::Block, usize),
-) -> ast::Def
-{
-    let __start0 = __5.0.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action134(
-        __5,
-        __6,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action8(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __temp0,
-        __7,
-    )
-}
-
-fn __action136<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, Vec<(String, ast::Type)>, usize),
-    __4: (usize, lexer::Token, usize),
-    __5: (usize, ast::Block, usize),
-) -> ast::Def
-{
-    let __start0 = __4.2.clone();
-    let __end0 = __5.0.clone();
-    let __temp0 = __action85(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action8(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __temp0,
-        __5,
-    )
-}
-
-fn __action137<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, lexer::Token, usize),
-    __7: (usize, lexer::Token, usize),
-    __8: (usize, ast::Type, usize),
-    __9: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __7.0.clone();
-    let __end0 = __8.2.clone();
-    let __temp0 = __action134(
-        __7,
-        __8,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __temp0,
-        __9,
-    )
-}
-
-fn __action138<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, lexer::Token, usize),
-    __7: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __6.2.clone();
-    let __end0 = __7.0.clone();
-    let __temp0 = __action85(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __temp0,
-        __7,
-    )
-}
-
-fn __action139<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, lexer::Token, usize),
-    __7: (usize, ast::Type, usize),
-    __8: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __6.0.clone();
-    let __end0 = __7.2.clone();
-    let __temp0 = __action134(
-        __6,
-        __7,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action124(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __temp0,
-        __8,
-    )
-}
-
-fn __action140<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, lexer::Token, usize),
-) -> ast::TopLevelDecl
-{
-    let __start0 = __5.2.clone();
-    let __end0 = __6.0.clone();
-    let __temp0 = __action85(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action124(
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __temp0,
-        __6,
-    )
-}
-
-fn __action141<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-    __3: (usize, ast::Block, usize),
-) -> ast::TopLevelDef
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action134(
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action6(
-        __0,
-        __temp0,
-        __3,
-    )
-}
-
-fn __action142<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Block, usize),
-) -> ast::TopLevelDef
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action85(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action6(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action143<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Type, usize),
-) -> core::option::Option<ast::Type>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action70(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action68(
-        __temp0,
-    )
-}
-
-fn __action144<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, ast::Type, usize),
-    __5: (usize, lexer::Token, usize),
-    __6: (usize, ast::Expression, usize),
-    __7: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __3.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action143(
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action127(
-        __0,
-        __1,
-        __2,
-        __temp0,
-        __5,
-        __6,
-        __7,
-    )
-}
-
-fn __action145<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, lexer::Token, usize),
-    __4: (usize, ast::Expression, usize),
-    __5: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __2.2.clone();
-    let __end0 = __3.0.clone();
-    let __temp0 = __action69(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action127(
-        __0,
-        __1,
-        __2,
-        __temp0,
-        __3,
-        __4,
-        __5,
-    )
-}
-
-fn __action146<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-    __4: (usize, lexer::Token, usize),
-    __5: (usize, ast::Expression, usize),
-    __6: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __2.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action143(
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action128(
-        __0,
-        __1,
-        __temp0,
-        __4,
-        __5,
-        __6,
-    )
-}
-
-fn __action147<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Expression, usize),
-    __4: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action69(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action128(
-        __0,
-        __1,
-        __temp0,
-        __2,
-        __3,
-        __4,
-    )
-}
-
-fn __action148<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> (String, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action93(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action78(
-        __temp0,
-        __2,
-    )
-}
-
-fn __action149<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-) -> core::option::Option<String>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action93(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action91(
-        __temp0,
-    )
-}
-
-fn __action150<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> core::option::Option<(String, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action148(
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action103(
-        __temp0,
-    )
-}
-
-fn __action151<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-    __3: (usize, lexer::Token, usize),
-) -> (String, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action148(
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action107(
-        __temp0,
-        __3,
-    )
-}
-
-fn __action152<
->(
-    __0: (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action150(
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action77(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action153<
->(
-    __0: (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action104(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action77(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action154<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action149(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action90(
-        __temp0,
-        __2,
-    )
-}
-
-fn __action155<
->(
-    __0: (usize, ast::Type, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action92(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action90(
-        __temp0,
-        __0,
-    )
-}
-
-fn __action156<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> core::option::Option<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action154(
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        __temp0,
-    )
-}
-
-fn __action157<
->(
-    __0: (usize, ast::Type, usize),
-) -> core::option::Option<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action155(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        __temp0,
-    )
-}
-
-fn __action158<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-    __3: (usize, lexer::Token, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action154(
-        __0,
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action102(
-        __temp0,
-        __3,
-    )
-}
-
-fn __action159<
->(
-    __0: (usize, ast::Type, usize),
-    __1: (usize, lexer::Token, usize),
-) -> (core::option::Option<String>, ast::Type)
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action155(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action102(
-        __temp0,
-        __1,
-    )
-}
-
-fn __action160<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action156(
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action89(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action161<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action157(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action89(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action162<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action99(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action89(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action163<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-    __3: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action151(
-        __0,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action119(
-        __temp0,
-    )
-}
-
-fn __action164<
->(
-    __0: (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-    __4: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(String, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action151(
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action120(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action165<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action105(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action152(
-        __temp0,
-        __0,
-        __1,
-        __2,
-    )
-}
-
-fn __action166<
->(
-    __0: (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action106(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action152(
-        __temp0,
-        __1,
-        __2,
-        __3,
-    )
-}
-
-fn __action167<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action105(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action153(
-        __temp0,
-    )
-}
-
-fn __action168<
->(
-    __0: (usize, alloc::vec::Vec<(String, ast::Type)>, usize),
-) -> Vec<(String, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action106(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action153(
-        __temp0,
-    )
-}
-
-fn __action169<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-    __3: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action158(
-        __0,
-        __1,
-        __2,
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action121(
-        __temp0,
-    )
-}
-
-fn __action170<
->(
-    __0: (usize, ast::Type, usize),
-    __1: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action159(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action121(
-        __temp0,
-    )
-}
-
-fn __action171<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-    __4: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action158(
-        __1,
-        __2,
-        __3,
-        __4,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action122(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action172<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, ast::Type, usize),
-    __2: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action159(
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action122(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action173<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-    __2: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action100(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action160(
-        __temp0,
-        __0,
-        __1,
-        __2,
-    )
-}
-
-fn __action174<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-    __3: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action101(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action160(
-        __temp0,
-        __1,
-        __2,
-        __3,
-    )
-}
-
-fn __action175<
->(
-    __0: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action100(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action161(
-        __temp0,
-        __0,
-    )
-}
-
-fn __action176<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-    __1: (usize, ast::Type, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action101(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action161(
-        __temp0,
-        __1,
-    )
-}
-
-fn __action177<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action100(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action162(
-        __temp0,
-    )
-}
-
-fn __action178<
->(
-    __0: (usize, alloc::vec::Vec<(core::option::Option<String>, ast::Type)>, usize),
-) -> Vec<(core::option::Option<String>, ast::Type)>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action101(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action162(
-        __temp0,
-    )
-}
-
-fn __action179<
->(
-    __0: (usize, ast::Expression, usize),
-    __1: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<ast::Expression>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action112(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action117(
-        __temp0,
-    )
-}
-
-fn __action180<
->(
-    __0: (usize, alloc::vec::Vec<ast::Expression>, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<ast::Expression>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action112(
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action118(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action181<
->(
-    __0: (usize, core::option::Option<ast::Expression>, usize),
-) -> Vec<ast::Expression>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action110(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action65(
-        __temp0,
-        __0,
-    )
-}
-
-fn __action182<
->(
-    __0: (usize, alloc::vec::Vec<ast::Expression>, usize),
-    __1: (usize, core::option::Option<ast::Expression>, usize),
-) -> Vec<ast::Expression>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action111(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action65(
-        __temp0,
-        __1,
-    )
-}
-
-fn __action183<
->(
-    __0: (usize, String, usize),
-    __1: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<String>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action62(
-        __0,
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action115(
-        __temp0,
-    )
-}
-
-fn __action184<
->(
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, lexer::Token, usize),
-) -> alloc::vec::Vec<String>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action62(
-        __1,
-        __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action116(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action185<
->(
-    __0: (usize, String, usize),
-) -> ast::NSIdent
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action60(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action59(
-        __temp0,
-        __0,
-    )
-}
-
-fn __action186<
->(
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-) -> ast::NSIdent
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action61(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action59(
-        __temp0,
-        __1,
-    )
-}
-
-fn __action187<
->(
-    __0: (usize, ast::Expression, usize),
-) -> Vec<ast::Expression>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action66(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action181(
-        __temp0,
-    )
-}
-
-fn __action188<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<ast::Expression>
-{
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action67(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action181(
-        __temp0,
-    )
-}
-
-fn __action189<
->(
-    __0: (usize, alloc::vec::Vec<ast::Expression>, usize),
-    __1: (usize, ast::Expression, usize),
-) -> Vec<ast::Expression>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action66(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action182(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action190<
->(
-    __0: (usize, alloc::vec::Vec<ast::Expression>, usize),
-) -> Vec<ast::Expression>
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action67(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action182(
-        __0,
-        __temp0,
-    )
-}
-
-fn __action191<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action66(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action23(
-        __0,
-        __temp0,
-        __2,
-    )
-}
-
-fn __action192<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action67(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action23(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action193<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action66(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action24(
-        __0,
-        __temp0,
-        __2,
-    )
-}
-
-fn __action194<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-) -> ast::Statement
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action67(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action24(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action195<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action75(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action11(
-        __0,
-        __temp0,
-        __1,
-        __2,
-    )
-}
-
-fn __action196<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, alloc::vec::Vec<ast::Statement>, usize),
-    __2: (usize, ast::Expression, usize),
-    __3: (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action76(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action11(
-        __0,
-        __temp0,
-        __2,
-        __3,
-    )
-}
-
-fn __action197<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action75(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action12(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action198<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, alloc::vec::Vec<ast::Statement>, usize),
-    __2: (usize, lexer::Token, usize),
-) -> ast::Block
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action76(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action12(
-        __0,
-        __temp0,
-        __2,
-    )
-}
-
-fn __action199<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, ast::Block, usize),
-    __3: (usize, Either<Box<ast::If>, Box<ast::Block>>, usize),
-) -> ast::If
-{
-    let __start0 = __3.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action73(
-        __3,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action16(
-        __0,
-        __1,
-        __2,
-        __temp0,
-    )
-}
-
-fn __action200<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, ast::Expression, usize),
-    __2: (usize, ast::Block, usize),
-) -> ast::If
-{
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action74(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action16(
-        __0,
-        __1,
-        __2,
-        __temp0,
-    )
-}
-
-fn __action201<
->(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<ast::TopLevelDecl>
-{
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action94(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action1(
-        __temp0,
-    )
-}
-
-fn __action202<
->(
-    __0: (usize, alloc::vec::Vec<ast::TopLevelDecl>, usize),
-) -> Vec<ast::TopLevelDecl>
-{
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action95(
-        __0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action1(
-        __temp0,
-    )
-}
-
-fn __action203<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, lexer::Token, usize),
-) -> ast::Type
-{
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action63(
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action58(
-        __0,
-        __temp0,
-        __1,
-    )
-}
-
-fn __action204<
->(
-    __0: (usize, lexer::Token, usize),
-    __1: (usize, alloc::vec::Vec<ast::Type>, usize),
-    __2: (usize, lexer::Token, usize),
-) -> ast::Type
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action64(
-        __1,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action58(
-        __0,
-        __temp0,
-        __2,
-    )
-}
-
-pub trait __ToTriple<> {
-    fn to_triple(value: Self) -> Result<(usize,lexer::Token,usize), __lalrpop_util::ParseError<usize, lexer::Token, LexError>>;
-}
-
-impl<> __ToTriple<> for (usize, lexer::Token, usize) {
-    fn to_triple(value: Self) -> Result<(usize,lexer::Token,usize), __lalrpop_util::ParseError<usize, lexer::Token, LexError>> {
-        Ok(value)
-    }
-}
-impl<> __ToTriple<> for Result<(usize, lexer::Token, usize), LexError> {
-    fn to_triple(value: Self) -> Result<(usize,lexer::Token,usize), __lalrpop_util::ParseError<usize, lexer::Token, LexError>> {
-        match value {
-            Ok(v) => Ok(v),
-            Err(error) => Err(__lalrpop_util::ParseError::User { error }),
-        }
-    }
-}
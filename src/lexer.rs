@@ -1,4 +1,7 @@
 use std::{ffi::CStr, os::raw::{c_char, c_int}};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::LexError;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
@@ -10,15 +13,21 @@ pub enum Token {
 	Fn,
 	Use,
 	Extern,
+	Export,
 	Return,
 	Yield,
 	Break,
+	Continue,
 	If,
 	Else,
 	Loop,
+	Match,
 	Static,
 	Entry,
 	Dyn,
+	As,
+	Sizeof,
+	Alignof,
 
 	OpenPar,
 	ClosePar,
@@ -37,6 +46,9 @@ pub enum Token {
 	Star,
 	Slash,
 	Percent,
+	Exclamation,
+	Tilde,
+	Question,
 	Equals,
 	DblEquals,
 	Greater,
@@ -46,8 +58,11 @@ pub enum Token {
 
 	SingleArrow,
 	DoubleArrow,
+	Underscore,
 
 	Identifier(String),
+	/// `'name`, without the quote
+	Label(String),
 	Integer(String),
 	Float(String),
 
@@ -57,6 +72,8 @@ pub enum Token {
 	CHeapString(Vec<u8>),
 	ByteStaticString(Vec<u8>),
 	ByteHeapString(Vec<u8>),
+	/// The code point of a character literal, or its byte if it was written as an `\x` escape
+	CharLiteral(u32),
 }
 
 #[allow(dead_code)] // The items are constructed by the next_token function, which rust cannot see
@@ -71,15 +88,21 @@ pub enum TokenDiscriminant {
 	Fn,
 	Use,
 	Extern,
+	Export,
 	Return,
 	Yield,
 	Break,
+	Continue,
 	If,
 	Else,
 	Loop,
+	Match,
 	Static,
 	Entry,
 	Dyn,
+	As,
+	Sizeof,
+	Alignof,
 
 	OpenPar,
 	ClosePar,
@@ -98,6 +121,9 @@ pub enum TokenDiscriminant {
 	Star,
 	Slash,
 	Percent,
+	Exclamation,
+	Tilde,
+	Question,
 	Equals,
 	DblEquals,
 	Greater,
@@ -107,8 +133,10 @@ pub enum TokenDiscriminant {
 
 	SingleArrow,
 	DoubleArrow,
+	Underscore,
 
 	Identifier,
+	Label,
 	Integer,
 	Float,
 
@@ -118,6 +146,11 @@ pub enum TokenDiscriminant {
 	CHeapString,
 	ByteStaticString,
 	ByteHeapString,
+	CharLiteral,
+
+	UnterminatedString,
+	UnterminatedComment,
+	InvalidEscape,
 }
 
 #[derive(Debug)]
@@ -128,19 +161,34 @@ pub struct TokenMeta {
 	pub skipped: u32,
 }
 
-pub fn lex() -> (Token, u32, u32) {
+/// The C lexer keeps all of its state in globals, so only one token stream can exist at a time
+static LEXER_IN_USE: AtomicBool = AtomicBool::new(false);
+
+/// Claim the C lexer. Returns `false` if it's already being used by someone else.
+pub fn acquire() -> bool {
+	LEXER_IN_USE.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_ok()
+}
+
+/// Give up a claim on the C lexer obtained from [`acquire`]
+pub fn release() {
+	LEXER_IN_USE.store(false, Ordering::Release);
+}
+
+pub fn lex() -> Result<(Token, u32, u32), LexError> {
 	let TokenMeta { token, length, skipped } = unsafe { next_token() };
-	(
+	Ok((
 		match token {
 			TokenDiscriminant::Identifier => Token::Identifier(unsafe { get_yytext() }),
+			TokenDiscriminant::Label => Token::Label(unsafe { get_yytext() }[1..].to_owned()),
 		    TokenDiscriminant::Integer => Token::Integer(unsafe { get_yytext() }),
 		    TokenDiscriminant::Float => Token::Float(unsafe { get_yytext() }),
-		    TokenDiscriminant::LokStaticString => Token::LokStaticString(unsafe { get_string_value() }),
-		    TokenDiscriminant::LokHeapString => Token::LokHeapString(unsafe { get_string_value() }),
-		    TokenDiscriminant::CStaticString => Token::CStaticString(unsafe { get_string_value() }),
-		    TokenDiscriminant::CHeapString => Token::CHeapString(unsafe { get_string_value() }),
-		    TokenDiscriminant::ByteStaticString => Token::ByteStaticString(unsafe { get_string_value() }),
-		    TokenDiscriminant::ByteHeapString => Token::ByteHeapString(unsafe { get_string_value() }),
+		    TokenDiscriminant::LokStaticString => Token::LokStaticString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::LokHeapString => Token::LokHeapString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::CStaticString => Token::CStaticString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::CHeapString => Token::CHeapString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::ByteStaticString => Token::ByteStaticString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::ByteHeapString => Token::ByteHeapString(unsafe { get_string_value() }?),
+		    TokenDiscriminant::CharLiteral => Token::CharLiteral(char_value(unsafe { get_string_value() }?)?),
 			
 		    TokenDiscriminant::Eof => Token::Eof,
 		    TokenDiscriminant::Let => Token::Let,
@@ -149,15 +197,21 @@ pub fn lex() -> (Token, u32, u32) {
 		    TokenDiscriminant::Fn => Token::Fn,
 		    TokenDiscriminant::Use => Token::Use,
 		    TokenDiscriminant::Extern => Token::Extern,
+		    TokenDiscriminant::Export => Token::Export,
 		    TokenDiscriminant::Return => Token::Return,
 		    TokenDiscriminant::Yield => Token::Yield,
 			TokenDiscriminant::Break => Token::Break,
+			TokenDiscriminant::Continue => Token::Continue,
 		    TokenDiscriminant::If => Token::If,
 		    TokenDiscriminant::Else => Token::Else,
 			TokenDiscriminant::Loop => Token::Loop,
+			TokenDiscriminant::Match => Token::Match,
 		    TokenDiscriminant::Static => Token::Static,
 		    TokenDiscriminant::Entry => Token::Entry,
 		    TokenDiscriminant::Dyn => Token::Dyn,
+		    TokenDiscriminant::As => Token::As,
+		    TokenDiscriminant::Sizeof => Token::Sizeof,
+		    TokenDiscriminant::Alignof => Token::Alignof,
 		    TokenDiscriminant::OpenPar => Token::OpenPar,
 		    TokenDiscriminant::ClosePar => Token::ClosePar,
 		    TokenDiscriminant::OpenBrace => Token::OpenBrace,
@@ -175,6 +229,9 @@ pub fn lex() -> (Token, u32, u32) {
 		    TokenDiscriminant::Star => Token::Star,
 		    TokenDiscriminant::Slash => Token::Slash,
 		    TokenDiscriminant::Percent => Token::Percent,
+		    TokenDiscriminant::Exclamation => Token::Exclamation,
+		    TokenDiscriminant::Tilde => Token::Tilde,
+		    TokenDiscriminant::Question => Token::Question,
 		    TokenDiscriminant::Equals => Token::Equals,
 		    TokenDiscriminant::DblEquals => Token::DblEquals,
 		    TokenDiscriminant::Greater => Token::Greater,
@@ -183,18 +240,42 @@ pub fn lex() -> (Token, u32, u32) {
 		    TokenDiscriminant::LessEqual => Token::LessEqual,
 		    TokenDiscriminant::SingleArrow => Token::SingleArrow,
 		    TokenDiscriminant::DoubleArrow => Token::DoubleArrow,
+		    TokenDiscriminant::Underscore => Token::Underscore,
+
+			TokenDiscriminant::UnterminatedString => Err(LexError::UnterminatedString)?,
+			TokenDiscriminant::UnterminatedComment => Err(LexError::UnterminatedComment)?,
+			// Relative to the start of this call, like `skipped`; the caller knows where that is
+			TokenDiscriminant::InvalidEscape => Err(LexError::InvalidEscape(unsafe { escapeStart } as usize))?,
 		},
 		length,
 		skipped,
-	)
+	))
 }
 
 unsafe fn get_yytext() -> String {
 	CStr::from_ptr(yytext).to_str().unwrap().to_owned()
 }
 
-unsafe fn get_string_value() -> Vec<u8> {
-	stringValue[0..stringLength as usize].to_owned()
+unsafe fn get_string_value() -> Result<Vec<u8>, LexError> {
+	let length = stringLength as usize;
+	if length > stringValue.len() {
+		Err(LexError::StringTooLong)
+	} else {
+		Ok(stringValue[0..length].to_owned())
+	}
+}
+
+/// The value of a character literal, which must be exactly one character
+fn char_value(bytes: Vec<u8>) -> Result<u32, LexError> {
+	if let [byte] = *bytes {
+		return Ok(byte as u32);
+	}
+	let text = String::from_utf8(bytes).map_err(|e| LexError::InvalidCharLiteral(String::from_utf8_lossy(e.as_bytes()).into_owned()))?;
+	let mut chars = text.chars();
+	match (chars.next(), chars.next()) {
+		(Some(c), None) => Ok(c as u32),
+		_ => Err(LexError::InvalidCharLiteral(text)),
+	}
 }
 
 #[link(name="lexer", link="static")]
@@ -202,6 +283,7 @@ extern "C" {
 	static yytext: *mut c_char;
 	static stringLength: u16;
 	static stringValue: [u8; 2048];
+	static escapeStart: u32;
 
 	fn next_token() -> TokenMeta;
 	pub fn set_input(filename: *const c_char) -> c_int;
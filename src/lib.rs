@@ -0,0 +1,174 @@
+//! The Lok compiler, as a library
+//!
+//! The `lok` binary is a thin wrapper around [`compile`]; everything it does can also be done from another crate.
+
+use std::ffi::CString;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+pub mod codegen;
+pub mod error;
+pub mod lexer;
+pub mod source;
+
+lalrpop_util::lalrpop_mod!(parser);
+
+use codegen::{ast, lir, Compiler};
+use error::Diagnostic;
+use lexer::Token;
+use source::SourceMap;
+
+/// Options controlling a single invocation of [`compile_file`]
+#[derive(Debug, Default)]
+pub struct CompileOptions {
+	/// If set, the LIR of the module is dumped to this path before it is compiled
+	pub lir_output: Option<PathBuf>,
+	/// Only accept `extern` declarations, producing a module of `declare`s without requiring an entry point
+	pub declare_only: bool,
+	/// How deeply expressions may be nested, or `None` for [`lir::DEFAULT_RECURSION_LIMIT`]
+	pub recursion_limit: Option<usize>,
+}
+
+/// The result of compiling a source file
+pub struct CompiledArtifact<'ctx> {
+	pub module: inkwell::module::Module<'ctx>,
+	/// Non-fatal diagnostics produced along the way
+	pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Lex and parse the file at `path`
+pub fn parse_file(path: impl AsRef<Path>) -> Result<Vec<ast::TopLevelDecl>, Vec<Diagnostic>> {
+	let lexer = Lexer::new(&path.as_ref().to_string_lossy()).map_err(|e| vec![e.into()])?;
+	parse(lexer)
+}
+
+/// Parse the tokens from `lexer`
+pub fn parse(lexer: Lexer) -> Result<Vec<ast::TopLevelDecl>, Vec<Diagnostic>> {
+	parser::LokFileParser::new().parse(lexer).map_err(|e| vec![e.into()])
+}
+
+/// Run the whole pipeline on the file at `path`, producing an LLVM module owned by `compiler`'s context
+pub fn compile_file<'ctx>(compiler: &'ctx Compiler, path: impl AsRef<Path>, options: &CompileOptions) -> Result<CompiledArtifact<'ctx>, Vec<Diagnostic>> {
+	let lexer = Lexer::new(&path.as_ref().to_string_lossy()).map_err(|e| vec![e.into()])?;
+	compile(compiler, lexer, options)
+}
+
+/// Run the whole pipeline on the tokens from `lexer`. Its [`source_map`](Lexer::source_map) can be kept beforehand to
+/// locate any diagnostics.
+pub fn compile<'ctx>(compiler: &'ctx Compiler, lexer: Lexer, options: &CompileOptions) -> Result<CompiledArtifact<'ctx>, Vec<Diagnostic>> {
+	let source_file_name = lexer.source_map().name().to_owned();
+	let decls = parse(lexer)?;
+	let name = lir::Ident::UnmangledItem("Dunno".to_owned());
+	let recursion_limit = options.recursion_limit.unwrap_or(lir::DEFAULT_RECURSION_LIMIT);
+	let mut module = if options.declare_only {
+		lir::Module::declarations_from_ast(name, decls, recursion_limit, compiler.target_widths())
+	} else {
+		lir::Module::from_ast(name, decls, recursion_limit, compiler.target_widths())
+	}.map_err(|e| vec![e.into()])?;
+	if let Some(lir_path) = &options.lir_output {
+		module.print_to_file(lir_path).map_err(|e| vec![e.into()])?;
+	}
+	let diagnostics = std::mem::take(&mut module.warnings);
+	let module = compiler.compile_lir_module(module);
+	module.set_source_file_name(&source_file_name);
+	Ok(CompiledArtifact {
+		module,
+		diagnostics,
+	})
+}
+
+pub struct Lexer {
+	/// Byte offsets of the most recently returned token. `end` is where the next token's trivia starts.
+	start: usize,
+	end: usize,
+	trivia: Range<usize>,
+	source_map: SourceMap,
+}
+
+impl Lexer {
+	pub fn new(path: &str) -> std::io::Result<Lexer> {
+		let source_map = SourceMap::from_file(path)?;
+		if !lexer::acquire() {
+			Err(std::io::Error::new(std::io::ErrorKind::Other, "the lexer is already in use by another Lexer"))?
+		}
+		// SAFETY: C String doesn't need to live longer than this function, so it's fine for it to be temporary
+		let errno = unsafe { lexer::set_input(#[allow(temporary_cstring_as_ptr)] CString::new(path).unwrap().as_ptr()) };
+		if errno > 0 {
+			lexer::release();
+			Err(std::io::Error::from_raw_os_error(errno))?
+		}
+		Ok(Lexer {
+			start: 0,
+			end: 0,
+			trivia: 0..0,
+			source_map,
+		})
+	}
+
+	/// The span of the whitespace and comments between the previous token and the one most recently returned
+	///
+	/// After the end of the file has been reached, this is the trivia at the end of the file.
+	pub fn leading_trivia(&self) -> Range<usize> {
+		self.trivia.clone()
+	}
+
+	/// The span of the token most recently returned, or an empty span at the end of the file once it has been reached
+	pub fn span(&self) -> Range<usize> {
+		self.start..self.end
+	}
+
+	/// Line and column information for the file being lexed
+	pub fn source_map(&self) -> &SourceMap {
+		&self.source_map
+	}
+}
+
+#[derive(Debug)]
+pub enum LexError {
+	/// A string literal was longer than the lexer's buffer
+	StringTooLong,
+	/// An integer literal was malformed or too large to represent
+	InvalidIntegerLiteral(String),
+	/// A float literal was malformed
+	InvalidFloatLiteral(String),
+	/// The file ended inside a string literal
+	UnterminatedString,
+	/// The file ended inside a block comment
+	UnterminatedComment,
+	/// A backslash in a string literal that doesn't start a known escape sequence. Contains the backslash's offset.
+	InvalidEscape(usize),
+	/// A character literal that doesn't contain exactly one character. Contains what it did contain.
+	InvalidCharLiteral(String),
+}
+
+impl Drop for Lexer {
+	fn drop(&mut self) {
+		lexer::release();
+	}
+}
+
+pub type Spanned<Tok, Loc, Error> = Result<(Loc, Tok, Loc), Error>;
+
+impl<'a> Iterator for Lexer {
+	type Item = Spanned<Token, usize, LexError>;
+
+	fn next(&mut self) -> Option<Spanned<Token, usize, LexError>> {
+		match lexer::lex() {
+			Ok((Token::Eof, _, skipped)) => {
+				self.trivia = self.end..self.end + skipped as usize;
+				self.start = self.trivia.end;
+				self.end = self.trivia.end;
+				None
+			},
+			Ok((token, len, skipped)) => {
+				// Whitespace and comments before the token are counted in `skipped`, not in the token itself
+				self.trivia = self.end..self.end + skipped as usize;
+				self.start = self.trivia.end;
+				self.end = self.start + len as usize;
+				Some(Ok((self.start, token, self.end)))
+			},
+			Err(LexError::InvalidEscape(offset)) => Some(Err(LexError::InvalidEscape(self.end + offset))),
+			Err(e) => Some(Err(e)),
+		}
+	}
+}
@@ -1,59 +1,130 @@
-use std::ffi::CString;
+use inkwell::context::Context;
+use inkwell::targets::{CodeModel, RelocMode};
+use lok::{codegen, CompileOptions, Lexer};
 
-mod codegen;
-mod error;
-mod lexer;
-
-use lexer::Token;
-
-#[path="gen/parser.rs"]
-mod parser;
-
-fn main() {
-	let file_path = std::env::args().nth(1).unwrap();
-	let lexer = Lexer::new(&file_path).unwrap();
-	let decls = parser::LokFileParser::new().parse(lexer).unwrap();
-	let module = codegen::lir::Module::from_ast(codegen::lir::Ident::UnmangledItem("Dunno".to_owned()), decls).unwrap();
-	module.print_to_file("todo.lir").unwrap();
-	let compiler = codegen::Compiler::new();
-	let compiled_mod = compiler.compile_lir_module(module);
-	// compiled_mod.verify().unwrap();
-	compiler.print_ir(&compiled_mod, "todo.ll");
-	compiler.write_module(&compiled_mod, "todo.o");
+enum Mode {
+	Compile,
+	PrintTokens,
+	DumpAst,
 }
 
-struct Lexer {
-	pos: usize,
+enum Emit {
+	Object,
+	Bitcode,
 }
 
-impl Lexer {
-	fn new(path: &str) -> std::io::Result<Lexer> {
-		// SAFETY: C String doesn't need to live longer than this function, so it's fine for it to be temporary
-		let errno = unsafe { lexer::set_input(#[allow(temporary_cstring_as_ptr)] CString::new(path).unwrap().as_ptr()) };
-		if errno > 0 {
-			Err(std::io::Error::from_raw_os_error(errno))?
+fn main() {
+	let mut mode = Mode::Compile;
+	let mut emit = Emit::Object;
+	let mut declare_only = false;
+	let mut recursion_limit = None;
+	let mut file_path = None;
+	let mut compiler_options = codegen::CompilerOptions::default();
+	let mut args = std::env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match &*arg {
+			"--version" => {
+				println!("lok {}", env!("CARGO_PKG_VERSION"));
+				return;
+			},
+			"--print-tokens" => mode = Mode::PrintTokens,
+			"--dump-ast" => mode = Mode::DumpAst,
+			"--declare-only" => declare_only = true,
+			"--recursion-limit" => recursion_limit = Some(args.next().and_then(|n| n.parse().ok()).unwrap_or_else(|| usage_error("expected a number after --recursion-limit"))),
+			"--emit" => emit = match args.next().as_deref() {
+				Some("object") => Emit::Object,
+				Some("bitcode") => Emit::Bitcode,
+				other => usage_error(&format!("unknown output kind {:?}", other)),
+			},
+			"--relocation-model" => compiler_options.reloc_mode = match args.next().as_deref() {
+				Some("default") => RelocMode::Default,
+				Some("static") => RelocMode::Static,
+				Some("pic") => RelocMode::PIC,
+				Some("dynamic-no-pic") => RelocMode::DynamicNoPic,
+				other => usage_error(&format!("unknown relocation model {:?}", other)),
+			},
+			"--code-model" => compiler_options.code_model = match args.next().as_deref() {
+				Some("default") => CodeModel::Default,
+				Some("small") => CodeModel::Small,
+				Some("kernel") => CodeModel::Kernel,
+				Some("medium") => CodeModel::Medium,
+				Some("large") => CodeModel::Large,
+				other => usage_error(&format!("unknown code model {:?}", other)),
+			},
+			"--entry-symbol" => compiler_options.entry_symbol = args.next().unwrap_or_else(|| usage_error("missing entry symbol")),
+			"--no-lifetime-markers" => compiler_options.lifetime_markers = false,
+			"--frame-pointers" => compiler_options.frame_pointers = true,
+			"--hidden-visibility" => compiler_options.hidden_visibility = true,
+			"--target" => compiler_options.target_triple = Some(args.next().unwrap_or_else(|| usage_error("missing target triple"))),
+			_ => file_path = Some(arg),
 		}
-		Ok(Lexer {
-			pos: 0,
-		})
 	}
-}
-
-#[derive(Debug)]
-pub enum LexError {
-	
-}
-
-pub type Spanned<Tok, Loc, Error> = Result<(Loc, Tok, Loc), Error>;
+	let file_path = file_path.unwrap_or_else(|| usage_error("no input file"));
+	let lexer = Lexer::new(&file_path).unwrap_or_else(|e| {
+		eprintln!("{}", lok::error::Diagnostic::from(e));
+		std::process::exit(1);
+	});
+	// The lexer is handed over to the parser, but diagnostics from every later stage still need locating
+	let source_map = lexer.source_map().clone();
 
-impl<'a> Iterator for Lexer {
-	type Item = Spanned<Token, usize, LexError>;
+	match mode {
+		Mode::Compile => {},
+		Mode::PrintTokens => {
+			for item in lexer {
+				match item {
+					Ok((start, token, end)) => println!("{}..{} {:?}", start, end, token),
+					Err(e) => {
+						eprintln!("{}", lok::error::Diagnostic::from(e).located(&source_map));
+						std::process::exit(1);
+					},
+				}
+			}
+			return;
+		},
+		Mode::DumpAst => {
+			let decls = lok::parse(lexer).unwrap_or_else(|diagnostics| {
+				for diagnostic in diagnostics {
+					eprintln!("{}", diagnostic.located(&source_map));
+				}
+				std::process::exit(1);
+			});
+			println!("{:#?}", decls);
+			return;
+		},
+	}
 
-	fn next(&mut self) -> Option<Spanned<Token, usize, LexError>> {
-		let (token, _len, _skipped) = lexer::lex();
-		match token {
-			Token::Eof => None,
-			token => Some(Ok((self.pos, token, self.pos))) //TODO: Position information
+	let compiler = codegen::Compiler::try_with_options(Context::create(), compiler_options).unwrap_or_else(|e| {
+		eprintln!("{}", lok::error::Diagnostic::from(e));
+		std::process::exit(1);
+	});
+	let artifact = lok::compile(&compiler, lexer, &CompileOptions {
+		lir_output: Some("todo.lir".into()),
+		declare_only,
+		recursion_limit,
+	}).unwrap_or_else(|diagnostics| {
+		for diagnostic in diagnostics {
+			eprintln!("{}", diagnostic.located(&source_map));
 		}
+		std::process::exit(1);
+	});
+	for diagnostic in &artifact.diagnostics {
+		eprintln!("{}", diagnostic.located(&source_map));
+	}
+	// artifact.module.verify().unwrap();
+	compiler.print_ir(&artifact.module, "todo.ll");
+	let written = match emit {
+		Emit::Object => compiler.write_module(&artifact.module, "todo.o"),
+		Emit::Bitcode => compiler.write_bitcode(&artifact.module, "todo.bc"),
+	};
+	if let Err(diagnostic) = written {
+		eprintln!("{}", diagnostic);
+		std::process::exit(1);
 	}
-}
\ No newline at end of file
+}
+
+fn usage_error(message: &str) -> ! {
+	eprintln!("error: {}", message);
+	eprintln!("usage: lok --version");
+	eprintln!("       lok [--print-tokens | --dump-ast] [--emit object|bitcode] [--declare-only] [--recursion-limit <n>] [--relocation-model <model>] [--code-model <model>] [--entry-symbol <name>] [--target <triple>] [--no-lifetime-markers] [--frame-pointers] [--hidden-visibility] <file>");
+	std::process::exit(2);
+}
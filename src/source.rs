@@ -0,0 +1,43 @@
+//! Turning byte offsets back into places a person can find in their editor
+
+use std::path::Path;
+
+/// The name of a source file and where each of its lines starts, for resolving spans into lines and columns
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+	name: String,
+	/// Byte offset of the first character of each line. Always starts with `0`.
+	line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+	pub fn new(name: impl Into<String>, source: &[u8]) -> SourceMap {
+		let line_starts = std::iter::once(0)
+			.chain(source.iter().enumerate().filter(|(_, &b)| b == b'\n').map(|(i, _)| i + 1))
+			.collect();
+		SourceMap {
+			name: name.into(),
+			line_starts,
+		}
+	}
+
+	/// Read the file at `path`, naming it by the path as given
+	pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<SourceMap> {
+		let source = std::fs::read(path.as_ref())?;
+		Ok(SourceMap::new(path.as_ref().to_string_lossy(), &source))
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// The 1-based line and column of a byte offset. Columns count bytes, not characters.
+	pub fn locate(&self, offset: usize) -> (u32, u32) {
+		// The line is the last one starting at or before the offset
+		let line = match self.line_starts.binary_search(&offset) {
+			Ok(line) => line,
+			Err(next) => next - 1,
+		};
+		((line + 1) as u32, (offset - self.line_starts[line] + 1) as u32)
+	}
+}
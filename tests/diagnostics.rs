@@ -0,0 +1,43 @@
+//! Messages reported for programs that are wrong or suspicious
+
+use std::path::Path;
+
+use lok::codegen::Compiler;
+use lok::error::Severity;
+use lok::source::SourceMap;
+use lok::CompileOptions;
+
+/// Fixtures in `tests/fixtures`, and the severity, message, and line and column, if any, of the one diagnostic each
+/// should produce
+const EXPECTED: &[(&str, Severity, &str, Option<(u32, u32)>)] = &[
+	("assign_type.lok", Severity::Error, "cannot assign to `u128`, which is not a variable", Some((2, 2))),
+	("unresolved_var.lok", Severity::Error, "unresolved identifier `cout`, did you mean `count`?", Some((3, 2))),
+	// Only the second `let` in the outer block warns; shadowing in the nested block is expected
+	("shadow_same_block.lok", Severity::Warning, "`x` is already bound in this block; this `let` shadows it", Some((6, 2))),
+	// The first break decides the loop's type, so it's the second that's wrong
+	("break_mismatch.lok", Severity::Error, "mismatched types", Some((6, 3))),
+	("void_value.lok", Severity::Error, "expression has no value", Some((4, 15))),
+];
+
+// The lexer can only read one file at a time, so the fixtures are compiled one after another in a single test
+#[test]
+fn fixtures_report_their_diagnostic() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+	let compiler = Compiler::new();
+	for (fixture, severity, message, location) in EXPECTED {
+		let path = root.join(fixture);
+		let diagnostics = match lok::compile_file(&compiler, &path, &CompileOptions::default()) {
+			Ok(artifact) => artifact.diagnostics,
+			Err(diagnostics) => diagnostics,
+		};
+		match diagnostics.as_slice() {
+			[diagnostic] => {
+				assert_eq!(diagnostic.severity, *severity, "{}", fixture);
+				assert_eq!(diagnostic.message, *message, "{}", fixture);
+				let source_map = SourceMap::from_file(&path).unwrap();
+				assert_eq!(diagnostic.span.map(|(start, _)| source_map.locate(start)), *location, "{}", fixture);
+			},
+			_ => panic!("{}: expected one diagnostic, got {:?}", fixture, diagnostics),
+		}
+	}
+}
@@ -0,0 +1,17 @@
+//! Writing compiled modules to disk
+
+use std::path::Path;
+
+use lok::codegen::Compiler;
+use lok::CompileOptions;
+
+#[test]
+fn unwritable_output_is_reported() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let compiler = Compiler::new();
+	let artifact = lok::compile_file(&compiler, root.join("tests/ir/exit_code.lok"), &CompileOptions::default())
+		.unwrap_or_else(|diagnostics| panic!("{:?}", diagnostics));
+	let missing = root.join("tests/no such directory/out");
+	assert!(compiler.write_bitcode(&artifact.module, missing.with_extension("bc")).is_err());
+	assert!(compiler.write_module(&artifact.module, missing.with_extension("o")).is_err());
+}
@@ -0,0 +1,83 @@
+//! Compiles each `.lok` fixture in `tests/ir` and compares the generated IR against the `.ll` file next to it
+//!
+//! Comments at the start of a fixture can set compiler options: `// target: <triple>` compiles for that target instead
+//! of the host, and `// frame-pointers` and `// hidden-visibility` turn on the options of the same name. Run with
+//! `LOK_BLESS=1` to overwrite the expected IR with whatever the compiler currently produces.
+
+use std::path::Path;
+
+use inkwell::context::Context;
+use lok::codegen::{Compiler, CompilerOptions};
+use lok::CompileOptions;
+
+/// Removes the parts of the IR that depend on the host or on LLVM's formatting rather than on the compiler
+fn normalize(ir: &str) -> String {
+	let mut lines = vec![];
+	for line in ir.lines() {
+		if line.starts_with(';') || line.starts_with("source_filename") || line.starts_with("target ") {
+			continue;
+		}
+		// Labels are followed by a padded `; preds = ...` or `; No predecessors!` comment
+		let line = match line.find(';') {
+			Some(i) if !line.starts_with(' ') => &line[..i],
+			_ => line,
+		};
+		// Alignment comes from the target's data layout
+		let mut line = line.to_owned();
+		while let Some(i) = line.find(", align ") {
+			let end = line[i + 8..].find(|c: char| !c.is_ascii_digit()).map(|e| i + 8 + e).unwrap_or(line.len());
+			line.replace_range(i..end, "");
+		}
+		lines.push(line.trim_end().replace(env!("CARGO_PKG_VERSION"), "<version>"));
+	}
+	lines.join("\n").trim().to_owned()
+}
+
+#[test]
+fn golden_ir() {
+	let bless = std::env::var_os("LOK_BLESS").is_some();
+	let mut fixtures = std::fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ir"))
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.filter(|path| path.extension().map(|e| e == "lok").unwrap_or(false))
+		.collect::<Vec<_>>();
+	fixtures.sort();
+
+	// The lexer can only be used by one file at a time, so the fixtures are compiled one after another
+	let mut failures = vec![];
+	for fixture in &fixtures {
+		let source = std::fs::read_to_string(fixture).unwrap();
+		let mut options = CompilerOptions::default();
+		for line in source.lines().take_while(|line| line.starts_with("//")) {
+			match line.trim_start_matches('/').trim() {
+				"frame-pointers" => options.frame_pointers = true,
+				"hidden-visibility" => options.hidden_visibility = true,
+				directive => if let Some(triple) = directive.strip_prefix("target:") {
+					options.target_triple = Some(triple.trim().to_owned());
+				},
+			}
+		}
+		let compiler = Compiler::with_options(Context::create(), options);
+		let artifact = match lok::compile_file(&compiler, fixture, &CompileOptions::default()) {
+			Ok(artifact) => artifact,
+			Err(diagnostics) => {
+				failures.push(format!("{}: failed to compile: {:?}", fixture.display(), diagnostics));
+				continue;
+			}
+		};
+		let actual = normalize(&compiler.ir_to_string(&artifact.module));
+		let expected_path = fixture.with_extension("ll");
+		if bless {
+			std::fs::write(&expected_path, actual + "\n").unwrap();
+			continue;
+		}
+		let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+		if normalize(&expected) != actual {
+			failures.push(format!("{}: IR differs from {}\n--- expected\n{}\n--- actual\n{}", fixture.display(), expected_path.display(), normalize(&expected), actual));
+		}
+	}
+
+	if !failures.is_empty() {
+		panic!("{} of {} fixtures failed:\n\n{}", failures.len(), fixtures.len(), failures.join("\n\n"));
+	}
+}
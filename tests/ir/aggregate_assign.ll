@@ -0,0 +1,25 @@
+define void @_LZ4copyE([4 x i32] %0) #0 {
+decl:
+  %a = alloca [4 x i32]
+  %b.0 = alloca [4 x i32]
+  store [4 x i32] %0, [4 x i32]* %a
+  br label %entry
+
+entry:
+  %1 = bitcast [4 x i32]* %b.0 to i8*
+  %2 = bitcast [4 x i32]* %a to i8*
+  call void @llvm.memmove.p0i8.p0i8.i64(i8* align 4 %1, i8* align 4 %2, i64 16, i1 false)
+  %3 = bitcast [4 x i32]* %b.0 to i8*
+  %4 = bitcast [4 x i32]* %b.0 to i8*
+  call void @llvm.memmove.p0i8.p0i8.i64(i8* align 4 %3, i8* align 4 %4, i64 16, i1 false)
+  ret void
+}
+
+declare void @llvm.memmove.p0i8.p0i8.i64(i8* nocapture, i8* nocapture readonly, i64, i1 immarg) #1
+
+attributes #0 = { "lok.name"="copy" }
+attributes #1 = { argmemonly nounwind willreturn }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
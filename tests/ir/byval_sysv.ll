@@ -0,0 +1,21 @@
+declare void @take([3 x i64]* byval align 8)
+
+define void @_LZ4passE([3 x i64] %0) #0 {
+decl:
+  %a = alloca [3 x i64]
+  store [3 x i64] %0, [3 x i64]* %a
+  %byvaltmp = alloca [3 x i64]
+  br label %entry
+
+entry:
+  %loadtmp = load [3 x i64], [3 x i64]* %a
+  store [3 x i64] %loadtmp, [3 x i64]* %byvaltmp
+  call void @take([3 x i64]* %byvaltmp)
+  ret void
+}
+
+attributes #0 = { "lok.name"="pass" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,19 @@
+declare void @take([3 x i64])
+
+define void @_LZ4passE([3 x i64] %0) #0 {
+decl:
+  %a = alloca [3 x i64]
+  store [3 x i64] %0, [3 x i64]* %a
+  br label %entry
+
+entry:
+  %loadtmp = load [3 x i64], [3 x i64]* %a
+  call void @take([3 x i64] %loadtmp)
+  ret void
+}
+
+attributes #0 = { "lok.name"="pass" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
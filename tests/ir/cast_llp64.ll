@@ -0,0 +1,24 @@
+define i64 @_LZ5widenE(i32 %0) #0 {
+decl:
+  br label %entry
+
+entry:
+  %casttmp = sext i32 %0 to i64
+  ret i64 %casttmp
+}
+
+define i32 @_LZ6narrowE(i64 %0) #1 {
+decl:
+  br label %entry
+
+entry:
+  %casttmp = trunc i64 %0 to i32
+  ret i32 %casttmp
+}
+
+attributes #0 = { "lok.name"="widen" }
+attributes #1 = { "lok.name"="narrow" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
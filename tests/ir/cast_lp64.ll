@@ -0,0 +1,22 @@
+define i64 @_LZ5widenE(i64 %0) #0 {
+decl:
+  br label %entry
+
+entry:
+  ret i64 %0
+}
+
+define i64 @_LZ6narrowE(i64 %0) #1 {
+decl:
+  br label %entry
+
+entry:
+  ret i64 %0
+}
+
+attributes #0 = { "lok.name"="widen" }
+attributes #1 = { "lok.name"="narrow" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,11 @@
+define i32 @main() {
+decl:
+  br label %entry
+
+entry:
+  ret i32 42
+}
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,23 @@
+define i32 @_LZ2idE(i32 %0) #0 {
+decl:
+  br label %entry
+
+entry:
+  ret i32 %0
+}
+
+define i32 @main() #1 {
+decl:
+  br label %entry
+
+entry:
+  %calltmp = call i32 @_LZ2idE(i32 42)
+  ret i32 %calltmp
+}
+
+attributes #0 = { "frame-pointer"="all" "lok.name"="id" }
+attributes #1 = { "frame-pointer"="all" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,32 @@
+define hidden i32 @_LZ2idE(i32 %0) #0 {
+decl:
+  br label %entry
+
+entry:
+  ret i32 %0
+}
+
+define i32 @exported(i32 %0) #1 {
+decl:
+  br label %entry
+
+entry:
+  %calltmp = call i32 @_LZ2idE(i32 %0)
+  ret i32 %calltmp
+}
+
+define i32 @main() {
+decl:
+  br label %entry
+
+entry:
+  %calltmp = call i32 @exported(i32 42)
+  ret i32 %calltmp
+}
+
+attributes #0 = { "lok.name"="id" }
+attributes #1 = { "lok.name"="exported" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
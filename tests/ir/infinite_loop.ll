@@ -0,0 +1,17 @@
+define i32 @main() {
+decl:
+  br label %entry
+
+entry:
+  br label %bb0
+
+bb0:
+  br label %bb0
+
+bb1:
+  unreachable
+}
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,24 @@
+define i32 @main() {
+decl:
+  %x.0 = alloca i32
+  br label %entry
+
+entry:
+  %lifetimeptr = bitcast i32* %x.0 to i8*
+  call void @llvm.lifetime.start.p0i8(i64 4, i8* %lifetimeptr)
+  store i32 42, i32* %x.0
+  %loadtmp = load i32, i32* %x.0
+  %lifetimeptr1 = bitcast i32* %x.0 to i8*
+  call void @llvm.lifetime.end.p0i8(i64 4, i8* %lifetimeptr1)
+  ret i32 %loadtmp
+}
+
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #0
+
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #0
+
+attributes #0 = { argmemonly nounwind willreturn }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,59 @@
+define i64 @_LZ5countE({ i64, i32* } %0) #0 {
+decl:
+  %s = alloca { i64, i32* }
+  store { i64, i32* } %0, { i64, i32* }* %s
+  br label %entry
+
+entry:
+  %loadtmp = load { i64, i32* }, { i64, i32* }* %s
+  %fieldtmp = extractvalue { i64, i32* } %loadtmp, 0
+  ret i64 %fieldtmp
+}
+
+define i64 @_LZ6unsizeE([4 x i32] %0) #1 {
+decl:
+  %a = alloca [4 x i32]
+  store [4 x i32] %0, [4 x i32]* %a
+  br label %entry
+
+entry:
+  %slicedata = getelementptr inbounds [4 x i32], [4 x i32]* %a, i32 0, i32 0
+  %slice = insertvalue { i64, i32* } { i64 4, i32* undef }, i32* %slicedata, 1
+  %calltmp = call i64 @_LZ5countE({ i64, i32* } %slice)
+  ret i64 %calltmp
+}
+
+define i64 @_LZ12unsize_valueE([4 x i32] %0) #2 {
+decl:
+  %a = alloca [4 x i32]
+  store [4 x i32] %0, [4 x i32]* %a
+  %slicetmp = alloca [4 x i32]
+  br label %entry
+
+entry:
+  br label %bb0
+
+bb0:
+  %loadtmp = load [4 x i32], [4 x i32]* %a
+  store [4 x i32] %loadtmp, [4 x i32]* %slicetmp
+  %slicedata = getelementptr inbounds [4 x i32], [4 x i32]* %slicetmp, i32 0, i32 0
+  %slice = insertvalue { i64, i32* } { i64 4, i32* undef }, i32* %slicedata, 1
+  %calltmp = call i64 @_LZ5countE({ i64, i32* } %slice)
+  ret i64 %calltmp
+}
+
+define i32 @main() {
+decl:
+  br label %entry
+
+entry:
+  ret i32 0
+}
+
+attributes #0 = { "lok.name"="count" }
+attributes #1 = { "lok.name"="unsize" }
+attributes #2 = { "lok.name"="unsize_value" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
@@ -0,0 +1,13 @@
+define i32 @_LZ7largestE() #0 {
+decl:
+  br label %entry
+
+entry:
+  ret i32 -1
+}
+
+attributes #0 = { "lok.name"="largest" }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"lok version <version>"}
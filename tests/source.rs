@@ -0,0 +1,29 @@
+//! Resolving byte offsets into lines and columns
+
+use lok::source::SourceMap;
+
+#[test]
+fn offsets_are_located_by_line_and_column() {
+	let map = SourceMap::new("test.lok", b"entry {\n\tfoo();\n}\n");
+	assert_eq!(map.name(), "test.lok");
+	assert_eq!(map.locate(0), (1, 1));
+	assert_eq!(map.locate(6), (1, 7));
+	// The newline itself is the last column of its line
+	assert_eq!(map.locate(7), (1, 8));
+	assert_eq!(map.locate(8), (2, 1));
+	assert_eq!(map.locate(9), (2, 2));
+	assert_eq!(map.locate(16), (3, 1));
+	// The end of the file is just past the last newline
+	assert_eq!(map.locate(18), (4, 1));
+}
+
+#[test]
+fn lexer_maps_the_file_it_reads() {
+	let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ir/exit_code.lok");
+	let lexer = lok::Lexer::new(path).unwrap();
+	let source = std::fs::read(path).unwrap();
+	let map = lexer.source_map();
+	assert_eq!(map.name(), path);
+	let last_line = source.iter().filter(|&&b| b == b'\n').count() as u32 + 1;
+	assert_eq!(map.locate(source.len()).0, last_line);
+}
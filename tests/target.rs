@@ -0,0 +1,34 @@
+//! Setting up a compiler for a target triple given by the user
+
+use inkwell::context::Context;
+use lok::codegen::{Compiler, CompilerOptions};
+use lok::error::TargetError;
+
+fn try_target(triple: &str) -> Result<Compiler, TargetError> {
+	Compiler::try_with_options(Context::create(), CompilerOptions { target_triple: Some(triple.to_owned()), ..CompilerOptions::default() })
+}
+
+#[test]
+fn triple_without_os_is_rejected() {
+	match try_target("x86_64") {
+		Err(TargetError::MalformedTriple(triple)) => assert_eq!(triple, "x86_64"),
+		Err(e) => panic!("expected a malformed triple error, got: {}", e),
+		Ok(_) => panic!("a triple with only an architecture was accepted"),
+	}
+}
+
+#[test]
+fn triple_without_vendor_is_accepted() {
+	if let Err(e) = try_target("x86_64-linux-gnu") {
+		panic!("{}", e);
+	}
+}
+
+#[test]
+fn literals_are_checked_against_the_targets_pointer_width() {
+	let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/usize_overflow.lok");
+	let lp64 = try_target("x86_64-unknown-linux-gnu").unwrap_or_else(|e| panic!("{}", e));
+	assert!(lok::compile_file(&lp64, &path, &lok::CompileOptions::default()).is_ok());
+	let ilp32 = try_target("i686-unknown-linux-gnu").unwrap_or_else(|e| panic!("{}", e));
+	assert!(lok::compile_file(&ilp32, &path, &lok::CompileOptions::default()).is_err());
+}